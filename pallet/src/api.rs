@@ -17,23 +17,176 @@ pub struct MoveApiEstimation {
     /// Substrate weight required for the complete extrinsic cost combined with the variable gas
     /// indicated in the [`Estimation`] struct.
     pub total_weight_including_gas_used: Weight,
+    /// Minimum gas limit, which has been verified to succeed with metered gas. `None` if the
+    /// execution fails anyway.
+    pub recommended_gas_limit: Option<u32>,
+}
+
+/// Gas estimation information of version 1 of the runtime API, without a recommended gas limit.
+#[derive(Clone, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub struct MoveApiEstimationV1 {
+    /// Gas used.
+    pub gas_used: u64,
+    /// Status code for the MoveVM execution.
+    pub vm_status_code: u64,
+    /// Substrate weight required for the complete extrinsic cost combined with the variable gas
+    /// indicated in the [`Estimation`] struct.
+    pub total_weight_including_gas_used: Weight,
+}
+
+impl From<MoveApiEstimationV1> for MoveApiEstimation {
+    fn from(estimation: MoveApiEstimationV1) -> Self {
+        Self {
+            gas_used: estimation.gas_used,
+            vm_status_code: estimation.vm_status_code,
+            total_weight_including_gas_used: estimation.total_weight_including_gas_used,
+            recommended_gas_limit: None,
+        }
+    }
+}
+
+/// Cheque limits of the signers of a script transaction during a gas estimation.
+#[derive(Clone, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub enum MoveApiChequeLimits<AccountId> {
+    /// Unlimited cheque limits, so insufficient balances don't show up in the estimation.
+    Unlimited,
+    /// The whole free balance of each signer.
+    FreeBalance,
+    /// Cheque limits of the listed signers, all other signers have a cheque limit of zero.
+    Limits(Vec<(AccountId, u128)>),
+}
+
+/// Change of a single entry in the MoveVM storage.
+#[derive(Clone, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub enum MoveApiStorageChange {
+    /// A new entry has been added with the given value.
+    Added(Vec<u8>),
+    /// An existing entry has been modified to the given value.
+    Modified(Vec<u8>),
+    /// An existing entry has been deleted.
+    Deleted,
+}
+
+/// Write operation on the MoveVM storage.
+#[derive(Clone, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub struct MoveApiWriteOp {
+    /// Key within the MoveVM storage.
+    pub key: Vec<u8>,
+    /// Applied change - values are BCS-encoded resources or module bytecode.
+    pub change: MoveApiStorageChange,
+}
+
+/// Balance change of a single account.
+#[derive(Clone, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub enum MoveApiBalanceChange {
+    /// The amount has been withdrawn from the account.
+    Withdrawn(u128),
+    /// The amount has been deposited to the account.
+    Deposited(u128),
+}
+
+/// Event emitted within Move code.
+#[derive(Clone, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub struct MoveApiEvent {
+    /// BCS-encoded type tag of the event.
+    pub type_tag: Vec<u8>,
+    /// BCS-encoded event data.
+    pub data: Vec<u8>,
+}
+
+/// Simulation result of a script execution.
+#[derive(Clone, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub struct MoveApiSimulation<AccountId> {
+    /// Gas estimation of the execution.
+    pub estimation: MoveApiEstimation,
+    /// All changes which would be applied to the MoveVM storage.
+    pub write_set: Vec<MoveApiWriteOp>,
+    /// All balance changes of involved accounts.
+    pub balance_changes: Vec<(AccountId, MoveApiBalanceChange)>,
+    /// All events emitted within Move code. Always empty, since the MoveVM backend doesn't report
+    /// emitted events yet.
+    pub events: Vec<MoveApiEvent>,
+}
+
+/// Module published under an account.
+#[derive(Clone, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub struct MoveApiModuleInfo {
+    /// Name of the module.
+    pub name: String,
+    /// Blake2s-256 hash of the module bytecode, if requested.
+    pub bytecode_hash: Option<[u8; 32]>,
+}
+
+/// Decoded value of a Move resource.
+#[derive(Clone, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub enum MoveApiValue {
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    /// Little-endian encoded 256-bit unsigned integer.
+    U256([u8; 32]),
+    Address([u8; 32]),
+    Vector(Vec<MoveApiValue>),
+    /// Struct with its named fields in declaration order.
+    Struct(Vec<(String, MoveApiValue)>),
+}
+
+/// Signer of a pending multi-signer execution request.
+#[derive(Clone, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub struct MoveApiMultisigSigner<AccountId> {
+    /// Account of the signer.
+    pub account: AccountId,
+    /// Whether the signer has already signed the request.
+    pub approved: bool,
+    /// Cheque limit of the signer, which is zero until the signer has signed the request.
+    pub cheque_limit: u128,
+}
+
+/// Pending multi-signer execution request.
+#[derive(Clone, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub struct MoveApiMultisigRequest<AccountId> {
+    /// Hash of the call, which identifies the request.
+    pub call_hash: [u8; 32],
+    /// All signers of the request with their signature states.
+    pub signers: Vec<MoveApiMultisigSigner<AccountId>>,
+    /// Block number at which the request gets removed, if it hasn't been executed until then.
+    pub expires_at: Option<u64>,
 }
 
 // Here we declare the runtime API. It is implemented it the `impl` block in
 // runtime file (the `runtime/src/lib.rs` of the node)
 sp_api::decl_runtime_apis! {
+    // Version 2 added a recommended gas limit to the estimations and all methods marked with it.
+    #[api_version(2)]
     pub trait MoveApi<AccountId> where      // AccountID is already here for the next API calls.
         AccountId: codec::Codec,
     {
         // Estimate gas for publishing a module.
+        #[changed_in(2)]
+        fn estimate_gas_publish_module(account: AccountId, bytecode: Vec<u8>) -> Result<MoveApiEstimationV1, DispatchError>;
         fn estimate_gas_publish_module(account: AccountId, bytecode: Vec<u8>) -> Result<MoveApiEstimation, DispatchError>;
 
         // Estimate gas for publishing a bundle.
+        #[changed_in(2)]
+        fn estimate_gas_publish_bundle(account: AccountId, bytecode: Vec<u8>) -> Result<MoveApiEstimationV1, DispatchError>;
         fn estimate_gas_publish_bundle(account: AccountId, bytecode: Vec<u8>) -> Result<MoveApiEstimation, DispatchError>;
 
         // Estimate gas for script execution.
+        #[changed_in(2)]
+        fn estimate_gas_execute_script(transaction: Vec<u8>) -> Result<MoveApiEstimationV1, DispatchError>;
         fn estimate_gas_execute_script(transaction: Vec<u8>) -> Result<MoveApiEstimation, DispatchError>;
 
+        // Estimate gas for script execution with the given cheque limits of the signers.
+        #[api_version(2)]
+        fn estimate_gas_execute_script_with_cheques(transaction: Vec<u8>, cheque_limits: MoveApiChequeLimits<AccountId>) -> Result<MoveApiEstimation, DispatchError>;
+
+        // Simulate script execution without applying any changes.
+        #[api_version(2)]
+        fn simulate_execute_script(transaction: Vec<u8>) -> Result<MoveApiSimulation<AccountId>, DispatchError>;
+
         // Get module binary by its address.
         fn get_module(address: AccountId, name: String) -> Result<Option<Vec<u8>>, Vec<u8>>;
 
@@ -42,5 +195,33 @@ sp_api::decl_runtime_apis! {
 
         // Get resource.
         fn get_resource(account: AccountId, tag: Vec<u8>) -> Result<Option<Vec<u8>>, Vec<u8>>;
+
+        // Get resource decoded with the layout of its struct.
+        #[api_version(2)]
+        fn get_resource_value(account: AccountId, tag: Vec<u8>) -> Result<Option<MoveApiValue>, Vec<u8>>;
+
+        // List the BCS-encoded type tags of all resources stored under the account.
+        #[api_version(2)]
+        fn list_resources(account: AccountId, start: Option<Vec<u8>>, limit: Option<u32>) -> Result<Vec<Vec<u8>>, Vec<u8>>;
+
+        // Get the name of an error returned by this pallet, e.g. for readable RPC errors.
+        #[api_version(2)]
+        fn error_name(error: DispatchError) -> Option<String>;
+
+        // List all modules published under the account, optionally with their bytecode hashes.
+        #[api_version(2)]
+        fn list_modules(account: AccountId, with_hashes: bool) -> Result<Vec<MoveApiModuleInfo>, Vec<u8>>;
+
+        // Get a pending multi-signer execution request by its call hash.
+        #[api_version(2)]
+        fn get_multisig_request(call_hash: [u8; 32]) -> Option<MoveApiMultisigRequest<AccountId>>;
+
+        // List all pending multi-signer execution requests the account is a signer of.
+        #[api_version(2)]
+        fn pending_multisig_requests_for(account: AccountId) -> Vec<MoveApiMultisigRequest<AccountId>>;
+
+        // Get the MoveVM status code an error of this pallet has been converted from.
+        #[api_version(2)]
+        fn error_vm_status(error: DispatchError) -> Option<u64>;
     }
 }
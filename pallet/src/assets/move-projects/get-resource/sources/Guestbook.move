@@ -0,0 +1,25 @@
+module DeveloperBob::Guestbook {
+    use std::signer;
+    use std::vector;
+
+    struct Guestbook has key {
+        guests: vector<address>
+    }
+
+    /// Creates an empty guestbook for the owner.
+    public fun create_guestbook(owner: &signer) {
+        move_to(owner, Guestbook { guests: vector::empty() });
+    }
+
+    /// Any account can sign the guestbook of another account, which grows the owner's storage.
+    public fun sign_guestbook(guest: &signer, owner: address) acquires Guestbook {
+        let guestbook = borrow_global_mut<Guestbook>(owner);
+        vector::push_back(&mut guestbook.guests, signer::address_of(guest));
+    }
+
+    /// Removes all guests from the owner's guestbook, which shrinks the owner's storage again.
+    public fun clear_guestbook(owner: &signer) acquires Guestbook {
+        let guestbook = borrow_global_mut<Guestbook>(signer::address_of(owner));
+        guestbook.guests = vector::empty();
+    }
+}
@@ -0,0 +1,12 @@
+module DeveloperBob::Registry {
+    use std::signer;
+
+    struct Registry has key {
+        owner: address
+    }
+
+    /// Executed automatically, when the module gets published for the first time.
+    fun init_module(publisher: &signer) {
+        move_to(publisher, Registry { owner: signer::address_of(publisher) });
+    }
+}
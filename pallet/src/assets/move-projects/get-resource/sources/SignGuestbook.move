@@ -0,0 +1,23 @@
+script {
+    use DeveloperBob::Guestbook;
+
+    fun create_guestbook(owner: signer) {
+        Guestbook::create_guestbook(&owner);
+    }
+}
+
+script {
+    use DeveloperBob::Guestbook;
+
+    fun sign_guestbook(guest: signer, owner: address) {
+        Guestbook::sign_guestbook(&guest, owner);
+    }
+}
+
+script {
+    use DeveloperBob::Guestbook;
+
+    fun clear_guestbook(owner: signer) {
+        Guestbook::clear_guestbook(&owner);
+    }
+}
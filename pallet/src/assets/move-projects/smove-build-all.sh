@@ -18,6 +18,7 @@ bundle_dir=(
     "basic_coin"
     "car-wash-example"
     "gas-costs-bundles"
+    "get-resource"
     "multiple-signers"
     "prohibited-bundle"
     "testing-move-stdlib"
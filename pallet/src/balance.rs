@@ -23,7 +23,7 @@ use sp_std::{
     vec::Vec,
 };
 
-use crate::{Config, Error, Pallet};
+use crate::{api::MoveApiBalanceChange, Config, Error, Pallet};
 
 // Shortcut type definitions for accessing more easily.
 pub type AccountIdOf<T> = <T as SysConfig>::AccountId;
@@ -88,6 +88,44 @@ impl<T: Config + SysConfig> BalanceAdapter<T> {
         Ok(handler)
     }
 
+    /// Creates a new [`BalanceAdapter`] with the whole free balance of every signer as their
+    /// cheque-limit, e.g. for a gas estimation which reveals insufficient balances.
+    pub fn for_free_balances(
+        args: &[&[u8]],
+        signer_count: usize,
+    ) -> Result<BalanceAdapter<T>, Error<T>> {
+        let mut handler = BalanceAdapter::new();
+
+        let accounts = Pallet::<T>::extract_account_ids_from_args(args, signer_count)?;
+
+        for acc in &accounts {
+            handler.write_cheque_internal(acc, &T::Currency::free_balance(acc));
+        }
+
+        Ok(handler)
+    }
+
+    /// Creates a new [`BalanceAdapter`] with the given cheque-limits, where signers without a
+    /// given cheque-limit can't withdraw anything. Cheque-limits of accounts, which aren't signers
+    /// of the script transaction, are ignored.
+    pub fn for_cheque_limits(
+        args: &[&[u8]],
+        signer_count: usize,
+        cheque_limits: &[(AccountIdOf<T>, BalanceOf<T>)],
+    ) -> Result<BalanceAdapter<T>, DispatchError> {
+        let mut handler = BalanceAdapter::new();
+
+        let accounts = Pallet::<T>::extract_account_ids_from_args(args, signer_count)?;
+
+        for (acc, cheque_limit) in cheque_limits {
+            if accounts.contains(acc) {
+                handler.write_cheque(acc, cheque_limit)?;
+            }
+        }
+
+        Ok(handler)
+    }
+
     /// Writes a cheque for the account.
     pub fn write_cheque(
         &mut self,
@@ -162,6 +200,32 @@ impl<T: Config + SysConfig> BalanceAdapter<T> {
         Ok(())
     }
 
+    /// Returns the balance changes of all accounts, which would be applied by
+    /// [`apply_transactions`](Self::apply_transactions).
+    pub(super) fn balance_changes(
+        &self,
+    ) -> Result<Vec<(AccountIdOf<T>, MoveApiBalanceChange)>, DispatchError> {
+        let zero = BalanceOf::<T>::zero();
+        let cheques = self.cheques.borrow();
+        let mut changes = Vec::new();
+
+        for (account, balance) in cheques.iter() {
+            let true_balance = self.initial_state.get(account).unwrap_or(&zero);
+            let change = match (*true_balance).cmp(balance) {
+                Ordering::Greater => {
+                    MoveApiBalanceChange::Withdrawn((*true_balance - *balance).into())
+                }
+                Ordering::Less => {
+                    MoveApiBalanceChange::Deposited((*balance - *true_balance).into())
+                }
+                Ordering::Equal => continue,
+            };
+            changes.push((vec_to_account_id::<T>(account)?, change));
+        }
+
+        Ok(changes)
+    }
+
     /// Ensures that user can withdraw that given amount of money, which eventually will be used
     /// within the Move-script execution.
     fn ensure_can_withdraw(
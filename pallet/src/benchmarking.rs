@@ -1,10 +1,12 @@
 //! Benchmarking setup for pallet-move.
 
 use frame_benchmarking::v2::*;
+use frame_support::{traits::Get, BoundedBTreeSet, BoundedVec};
 use frame_system::{Config as SysConfig, RawOrigin};
 use sp_core::crypto::Ss58Codec;
+use sp_std::{vec, vec::Vec};
 
-use crate::{mock_utils as utils, *};
+use crate::{balance::BalanceOf, mock_utils as utils, signer::ScriptSignatureHandler, *};
 
 type SourceOf<T> = <<T as SysConfig>::Lookup as sp_runtime::traits::StaticLookup>::Source;
 
@@ -150,11 +152,161 @@ mod benchmarks {
     }
 
     #[benchmark]
-    fn update_stdlib_bundle() {
+    fn update_stdlib_bundle() -> Result<(), BenchmarkError> {
+        let origin = T::StdlibUpdateOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
         let stdlib = core::include_bytes!("assets/move-projects/testing-substrate-stdlib/build/testing-substrate-stdlib/bundles/testing-substrate-stdlib.mvb").to_vec();
 
         #[extrinsic_call]
-        update_stdlib_bundle(RawOrigin::Root, stdlib);
+        update_stdlib_bundle(origin as T::RuntimeOrigin, stdlib);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn add_reserved_address() -> Result<(), BenchmarkError> {
+        let origin = T::ReservedAddressOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let bob_32 = utils::account::<T>(utils::BOB_ADDR);
+
+        #[extrinsic_call]
+        add_reserved_address(origin as T::RuntimeOrigin, bob_32);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_reserved_address() -> Result<(), BenchmarkError> {
+        let origin = T::ReservedAddressOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let bob_32 = utils::account::<T>(utils::BOB_ADDR);
+        ReservedAddresses::<T>::insert(&bob_32, ());
+
+        #[extrinsic_call]
+        remove_reserved_address(origin as T::RuntimeOrigin, bob_32);
+
+        Ok(())
+    }
+
+    /// The bundles from the `publish_module_generic` benchmark contain one more module per step.
+    #[benchmark]
+    fn publish_framework_bundle(n: Linear<1, 25>) -> Result<(), BenchmarkError> {
+        let origin = T::ReservedAddressOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let bob_32 = utils::account::<T>(utils::BOB_ADDR);
+        ReservedAddresses::<T>::insert(&bob_32, ());
+
+        let bundles = [
+            bundle1().to_vec(),
+            bundle2().to_vec(),
+            bundle3().to_vec(),
+            bundle4().to_vec(),
+            bundle5().to_vec(),
+            bundle6().to_vec(),
+            bundle7().to_vec(),
+            bundle8().to_vec(),
+            bundle9().to_vec(),
+            bundle10().to_vec(),
+            bundle11().to_vec(),
+            bundle12().to_vec(),
+            bundle13().to_vec(),
+            bundle14().to_vec(),
+            bundle15().to_vec(),
+            bundle16().to_vec(),
+            bundle17().to_vec(),
+            bundle18().to_vec(),
+            bundle19().to_vec(),
+            bundle20().to_vec(),
+            bundle21().to_vec(),
+            bundle22().to_vec(),
+            bundle23().to_vec(),
+            bundle24().to_vec(),
+            bundle25().to_vec(),
+        ];
+
+        #[extrinsic_call]
+        publish_framework_bundle(
+            origin as T::RuntimeOrigin,
+            bob_32,
+            bundles[n as usize - 1].clone(),
+            n,
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel_multisig_request(s: Linear<2, { T::MaxScriptSigners::get() }>) {
+        let signers: Vec<T::AccountId> = (0..s).map(|i| account("signer", i, 0)).collect();
+        let call_hash = [0u8; 32];
+        let cheque_limit: BalanceOf<T> = 0u128.into();
+
+        // All signers except the first one have signed the request and locked their funds, so
+        // the first one cancels the request with the most locks to release.
+        let mut accounts = BoundedBTreeSet::<T::AccountId, T::MaxScriptSigners>::new();
+        for signer in &signers {
+            accounts.try_insert(signer.clone()).unwrap();
+        }
+        let mut signature_handler = ScriptSignatureHandler::<T>::new(accounts).unwrap();
+        for signer in &signers[1..] {
+            let lock_id = Pallet::<T>::multi_signer_lock_id(signer, &call_hash, &cheque_limit);
+            signature_handler
+                .sign_script(signer, &cheque_limit, lock_id)
+                .unwrap();
+        }
+
+        let block_height = frame_system::Pallet::<T>::block_number();
+        let mut sig_data = signature_handler.into_inner();
+        sig_data.set_block_height(block_height);
+        for signer in &signers {
+            MultisigRequestsBySigner::<T>::insert(signer, call_hash, ());
+        }
+        MultisigStorage::<T>::insert(call_hash, sig_data);
+        ChoreOnIdleStorage::<T>::insert(
+            block_height + T::MultisigReqExpireTime::get(),
+            BoundedVec::truncate_from(vec![call_hash]),
+        );
+
+        #[extrinsic_call]
+        cancel_multisig_request(RawOrigin::Signed(signers[0].clone()), call_hash);
+    }
+
+    #[benchmark]
+    fn revoke_multisig_signature() {
+        let alice_32 = utils::account::<T>(utils::ALICE_ADDR);
+        let transaction_bc = rent_apartment().to_vec();
+        let call_hash = Pallet::<T>::transaction_bc_call_hash(&transaction_bc);
+
+        // Alice signs a multi-signer request, which locks her funds.
+        Pallet::<T>::execute(
+            RawOrigin::Signed(alice_32.clone()).into(),
+            transaction_bc,
+            MAX_GAS_AMOUNT,
+            0u128.into(),
+        )
+        .unwrap();
+
+        #[extrinsic_call]
+        revoke_multisig_signature(RawOrigin::Signed(alice_32), call_hash);
+    }
+
+    #[benchmark]
+    fn set_upgrade_policy() {
+        let bob_32 = utils::account::<T>(utils::BOB_ADDR);
+
+        Pallet::<T>::publish_module(
+            RawOrigin::Signed(bob_32.clone()).into(),
+            publish_basic_coin().to_vec(),
+            MAX_GAS_AMOUNT,
+        )
+        .unwrap();
+
+        #[extrinsic_call]
+        set_upgrade_policy(
+            RawOrigin::Signed(bob_32),
+            b"BasicCoin".to_vec(),
+            upgrade::UpgradePolicy::Immutable,
+        );
     }
 
     #[cfg(test)]
@@ -200,6 +352,13 @@ mod benchmark_only {
         )
     }
 
+    // Multiple Signers Example
+    pub fn rent_apartment() -> &'static [u8] {
+        core::include_bytes!(
+            "assets/move-projects/multiple-signers/build/multiple-signers/script_transactions/rent_apartment.mvt"
+        )
+    }
+
     impl_gas_costs_cal_fns!(mint_1);
     impl_gas_costs_cal_fns!(mint_2);
     impl_gas_costs_cal_fns!(mint_3);
@@ -0,0 +1,122 @@
+//! Helpers for inspecting Move bytecode and call parameters from within the pallet.
+//!
+//! Scripts are verified by the MoveVM backend before execution, but functions of already
+//! published modules need some additional checks on the pallet side, e.g. to find out how many
+//! signers an entry function expects.
+
+use move_binary_format::{
+    access::ModuleAccess,
+    file_format::{CompiledModule, SignatureToken, Visibility},
+};
+use move_core_types::{
+    ident_str,
+    identifier::{IdentStr, Identifier},
+    language_storage::{ModuleId, TypeTag},
+};
+
+use crate::{Config, Error};
+
+/// Returns the number of signers an entry function within the given module expects.
+///
+/// Like in scripts, all signers (`signer` or `&signer`) have to be placed in front of the
+/// remaining function arguments. The function fails if the module cannot be deserialized, if the
+/// function cannot be found, if it isn't declared as an `entry` function or if any other argument
+/// contains a signer.
+pub(crate) fn entry_function_signer_count<T: Config>(
+    module_bytecode: &[u8],
+    function: &IdentStr,
+) -> Result<usize, Error<T>> {
+    let module = CompiledModule::deserialize(module_bytecode)
+        .map_err(|_| Error::<T>::CodeDeserializationError)?;
+
+    let function_def = module
+        .function_defs()
+        .iter()
+        .find(|def| module.identifier_at(module.function_handle_at(def.function).name) == function)
+        .ok_or(Error::<T>::FunctionResolutionFailure)?;
+
+    if !function_def.is_entry {
+        return Err(Error::<T>::FunctionNotEntry);
+    }
+
+    let handle = module.function_handle_at(function_def.function);
+    let parameters = &module.signature_at(handle.parameters).0;
+
+    let signer_count = parameters.iter().take_while(|t| is_signer(t)).count();
+
+    // Signers that are not placed at the beginning of the list could be forged by the caller.
+    if parameters[signer_count..].iter().any(contains_signer) {
+        return Err(Error::<T>::ScriptSignatureFailure);
+    }
+
+    Ok(signer_count)
+}
+
+/// Returns the name of the given module or `None` if the bytecode cannot be deserialized.
+pub(crate) fn module_name(module_bytecode: &[u8]) -> Option<Identifier> {
+    CompiledModule::deserialize(module_bytecode)
+        .ok()
+        .map(|module| module.self_id().name().to_owned())
+}
+
+/// Name of the function that is executed when a module gets published for the first time.
+pub(crate) const INIT_MODULE: &IdentStr = ident_str!("init_module");
+
+/// Returns the ID of the given module, if it declares an [`INIT_MODULE`] function.
+///
+/// Like on other Move chains, the function has to be private, has no type parameters and return
+/// values and takes the publisher's `&signer` as its only argument. Functions with the same name
+/// but another signature are ignored, so that they can still be called in the regular way.
+pub(crate) fn init_module_id(module_bytecode: &[u8]) -> Option<ModuleId> {
+    let module = CompiledModule::deserialize(module_bytecode).ok()?;
+
+    let has_init_module = module.function_defs().iter().any(|def| {
+        let handle = module.function_handle_at(def.function);
+        let takes_signer_ref = match &module.signature_at(handle.parameters).0[..] {
+            [SignatureToken::Reference(inner)] => **inner == SignatureToken::Signer,
+            _ => false,
+        };
+
+        module.identifier_at(handle.name) == INIT_MODULE
+            && def.visibility == Visibility::Private
+            && !def.is_entry
+            && handle.type_parameters.is_empty()
+            && module.signature_at(handle.return_).0.is_empty()
+            && takes_signer_ref
+    });
+
+    has_init_module.then(|| module.self_id())
+}
+
+/// Checks whether any of the given type arguments would instantiate a signer.
+pub(crate) fn type_args_contain_signer(type_args: &[TypeTag]) -> bool {
+    type_args.iter().any(type_tag_contains_signer)
+}
+
+fn is_signer(token: &SignatureToken) -> bool {
+    match token {
+        SignatureToken::Signer => true,
+        SignatureToken::Reference(inner) => matches!(**inner, SignatureToken::Signer),
+        _ => false,
+    }
+}
+
+fn contains_signer(token: &SignatureToken) -> bool {
+    match token {
+        SignatureToken::Signer => true,
+        SignatureToken::Vector(inner)
+        | SignatureToken::Reference(inner)
+        | SignatureToken::MutableReference(inner) => contains_signer(inner),
+        SignatureToken::StructInstantiation(_, type_args) => type_args.iter().any(contains_signer),
+        _ => false,
+    }
+}
+
+fn type_tag_contains_signer(type_tag: &TypeTag) -> bool {
+    match type_tag {
+        TypeTag::Signer => true,
+        TypeTag::Vector(inner) => type_tag_contains_signer(inner),
+        TypeTag::Struct(tag) => tag.type_params.iter().any(type_tag_contains_signer),
+        _ => false,
+    }
+}
@@ -0,0 +1,43 @@
+//! Storage deposits for the data held in the MoveVM storage.
+//!
+//! Every account pays a deposit for the modules and resources stored under its address. The
+//! deposit is reserved when the storage usage grows and gets unreserved again when the data is
+//! removed.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::RuntimeDebug;
+use scale_info::TypeInfo;
+use sp_runtime::traits::{AtLeast32BitUnsigned, Saturating};
+
+use crate::storage::StorageUsageDelta;
+
+/// Storage usage of an account and the currently reserved deposit for it.
+#[derive(
+    Clone, Copy, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct StorageDeposit<Balance> {
+    /// Number of bytes stored, including the keys.
+    pub bytes: u64,
+    /// Number of stored items.
+    pub items: u64,
+    /// Reserved deposit.
+    pub amount: Balance,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy + From<u128>> StorageDeposit<Balance> {
+    /// Applies a storage usage change.
+    pub fn apply(&mut self, delta: &StorageUsageDelta) {
+        self.bytes = self.bytes.saturating_add_signed(delta.bytes);
+        self.items = self.items.saturating_add_signed(delta.items);
+    }
+
+    /// Calculates the deposit required for the current storage usage.
+    pub fn required(&self, per_byte: Balance, per_item: Balance) -> Balance {
+        let bytes = Balance::from(u128::from(self.bytes));
+        let items = Balance::from(u128::from(self.items));
+
+        per_byte
+            .saturating_mul(bytes)
+            .saturating_add(per_item.saturating_mul(items))
+    }
+}
@@ -0,0 +1,245 @@
+//! Type layouts of Move resources, resolved from the bytecode of the published modules.
+//!
+//! Resources are stored as plain BCS bytes, which don't contain any type information. With the
+//! layout of the resource's struct, the pallet can decode them into a self-describing
+//! [`MoveApiValue`], so clients don't have to resolve the layouts on their own.
+
+extern crate alloc;
+
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+use move_binary_format::{
+    access::ModuleAccess,
+    file_format::{CompiledModule, SignatureToken, StructFieldInformation, StructHandleIndex},
+};
+use move_core_types::{
+    identifier::IdentStr,
+    language_storage::{ModuleId, StructTag, TypeTag},
+};
+
+use crate::api::MoveApiValue;
+
+/// Layout of a storable Move value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum MoveLayout {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    U256,
+    Address,
+    Vector(Box<MoveLayout>),
+    /// Struct with its named fields in declaration order.
+    Struct(Vec<(String, MoveLayout)>),
+}
+
+impl MoveLayout {
+    /// Decodes a BCS-encoded value of this layout, which has to consume the whole input.
+    pub(crate) fn decode(&self, mut input: &[u8]) -> Option<MoveApiValue> {
+        let value = self.decode_from(&mut input)?;
+        input.is_empty().then_some(value)
+    }
+
+    fn decode_from(&self, input: &mut &[u8]) -> Option<MoveApiValue> {
+        let value = match self {
+            Self::Bool => match take(input)? {
+                [0] => MoveApiValue::Bool(false),
+                [1] => MoveApiValue::Bool(true),
+                _ => return None,
+            },
+            Self::U8 => MoveApiValue::U8(u8::from_le_bytes(take(input)?)),
+            Self::U16 => MoveApiValue::U16(u16::from_le_bytes(take(input)?)),
+            Self::U32 => MoveApiValue::U32(u32::from_le_bytes(take(input)?)),
+            Self::U64 => MoveApiValue::U64(u64::from_le_bytes(take(input)?)),
+            Self::U128 => MoveApiValue::U128(u128::from_le_bytes(take(input)?)),
+            Self::U256 => MoveApiValue::U256(take(input)?),
+            Self::Address => MoveApiValue::Address(take(input)?),
+            Self::Vector(layout) => {
+                let len = read_uleb128(input)?;
+                // Every element takes at least one byte, which prevents huge allocations.
+                if len > input.len() {
+                    return None;
+                }
+                let values = (0..len)
+                    .map(|_| layout.decode_from(input))
+                    .collect::<Option<_>>()?;
+                MoveApiValue::Vector(values)
+            }
+            Self::Struct(fields) => {
+                let values = fields
+                    .iter()
+                    .map(|(name, layout)| Some((name.clone(), layout.decode_from(input)?)))
+                    .collect::<Option<_>>()?;
+                MoveApiValue::Struct(values)
+            }
+        };
+
+        Some(value)
+    }
+}
+
+/// Resolves the layout of a struct, where `get_module` provides the bytecode of the published
+/// modules declaring the struct and all of its nested structs.
+pub(crate) fn struct_layout(
+    tag: &StructTag,
+    get_module: &impl Fn(&ModuleId) -> Option<Vec<u8>>,
+) -> Result<MoveLayout, String> {
+    let type_args = tag
+        .type_params
+        .iter()
+        .map(|type_tag| type_tag_layout(type_tag, get_module))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    declared_struct_layout(
+        &tag.module_id(),
+        tag.name.as_ident_str(),
+        &type_args,
+        get_module,
+    )
+}
+
+fn type_tag_layout(
+    type_tag: &TypeTag,
+    get_module: &impl Fn(&ModuleId) -> Option<Vec<u8>>,
+) -> Result<MoveLayout, String> {
+    let layout = match type_tag {
+        TypeTag::Bool => MoveLayout::Bool,
+        TypeTag::U8 => MoveLayout::U8,
+        TypeTag::U16 => MoveLayout::U16,
+        TypeTag::U32 => MoveLayout::U32,
+        TypeTag::U64 => MoveLayout::U64,
+        TypeTag::U128 => MoveLayout::U128,
+        TypeTag::U256 => MoveLayout::U256,
+        TypeTag::Address => MoveLayout::Address,
+        TypeTag::Vector(inner) => MoveLayout::Vector(Box::new(type_tag_layout(inner, get_module)?)),
+        TypeTag::Struct(tag) => struct_layout(tag, get_module)?,
+        TypeTag::Signer => return Err("signers cannot be stored".into()),
+    };
+
+    Ok(layout)
+}
+
+fn declared_struct_layout(
+    module_id: &ModuleId,
+    name: &IdentStr,
+    type_args: &[MoveLayout],
+    get_module: &impl Fn(&ModuleId) -> Option<Vec<u8>>,
+) -> Result<MoveLayout, String> {
+    let bytecode = get_module(module_id).ok_or_else(|| format!("module {module_id} not found"))?;
+    let module = CompiledModule::deserialize(&bytecode)
+        .map_err(|e| format!("invalid module {module_id}: {e:?}"))?;
+
+    let struct_def = module
+        .struct_defs()
+        .iter()
+        .find(|def| module.identifier_at(module.struct_handle_at(def.struct_handle).name) == name)
+        .ok_or_else(|| format!("struct {name} not found in module {module_id}"))?;
+
+    let StructFieldInformation::Declared(fields) = &struct_def.field_information else {
+        return Err(format!("native struct {name} has no layout"));
+    };
+
+    let fields = fields
+        .iter()
+        .map(|field| {
+            let layout = token_layout(&module, &field.signature.0, type_args, get_module)?;
+            Ok((
+                String::from(module.identifier_at(field.name).as_str()),
+                layout,
+            ))
+        })
+        .collect::<Result<_, String>>()?;
+
+    Ok(MoveLayout::Struct(fields))
+}
+
+fn token_layout(
+    module: &CompiledModule,
+    token: &SignatureToken,
+    type_args: &[MoveLayout],
+    get_module: &impl Fn(&ModuleId) -> Option<Vec<u8>>,
+) -> Result<MoveLayout, String> {
+    let layout = match token {
+        SignatureToken::Bool => MoveLayout::Bool,
+        SignatureToken::U8 => MoveLayout::U8,
+        SignatureToken::U16 => MoveLayout::U16,
+        SignatureToken::U32 => MoveLayout::U32,
+        SignatureToken::U64 => MoveLayout::U64,
+        SignatureToken::U128 => MoveLayout::U128,
+        SignatureToken::U256 => MoveLayout::U256,
+        SignatureToken::Address => MoveLayout::Address,
+        SignatureToken::Vector(inner) => MoveLayout::Vector(Box::new(token_layout(
+            module, inner, type_args, get_module,
+        )?)),
+        SignatureToken::Struct(handle) => nested_struct_layout(module, *handle, &[], get_module)?,
+        SignatureToken::StructInstantiation(handle, tokens) => {
+            let struct_type_args = tokens
+                .iter()
+                .map(|token| token_layout(module, token, type_args, get_module))
+                .collect::<Result<Vec<_>, _>>()?;
+            nested_struct_layout(module, *handle, &struct_type_args, get_module)?
+        }
+        SignatureToken::TypeParameter(index) => type_args
+            .get(usize::from(*index))
+            .cloned()
+            .ok_or("missing type argument")?,
+        SignatureToken::Signer
+        | SignatureToken::Reference(_)
+        | SignatureToken::MutableReference(_) => {
+            return Err("signers and references cannot be stored".into())
+        }
+    };
+
+    Ok(layout)
+}
+
+// Resolves the layout of a struct used within the given module, which might be declared in
+// another module.
+fn nested_struct_layout(
+    module: &CompiledModule,
+    handle: StructHandleIndex,
+    type_args: &[MoveLayout],
+    get_module: &impl Fn(&ModuleId) -> Option<Vec<u8>>,
+) -> Result<MoveLayout, String> {
+    let struct_handle = module.struct_handle_at(handle);
+    let module_handle = module.module_handle_at(struct_handle.module);
+    let module_id = ModuleId::new(
+        *module.address_identifier_at(module_handle.address),
+        module.identifier_at(module_handle.name).to_owned(),
+    );
+
+    declared_struct_layout(
+        &module_id,
+        module.identifier_at(struct_handle.name),
+        type_args,
+        get_module,
+    )
+}
+
+// Takes the next `N` bytes from the input.
+fn take<const N: usize>(input: &mut &[u8]) -> Option<[u8; N]> {
+    if input.len() < N {
+        return None;
+    }
+
+    let (bytes, rest) = input.split_at(N);
+    *input = rest;
+    bytes.try_into().ok()
+}
+
+// Reads a ULEB128-encoded length prefix, as used by BCS for vectors.
+fn read_uleb128(input: &mut &[u8]) -> Option<usize> {
+    let mut value: u64 = 0;
+
+    for shift in (0..64).step_by(7) {
+        let [byte] = take(input)?;
+        value |= u64::from(byte & 0x7f) << shift;
+
+        if byte & 0x80 == 0 {
+            return usize::try_from(value).ok();
+        }
+    }
+
+    None
+}
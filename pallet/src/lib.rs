@@ -5,6 +5,10 @@ pub mod api;
 pub mod balance;
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
+mod bytecode;
+pub mod deposit;
+mod layout;
+pub mod migrations;
 #[cfg(test)]
 pub(crate) mod mock;
 #[cfg(any(test, feature = "runtime-benchmarks"))]
@@ -14,6 +18,7 @@ mod signer;
 mod storage;
 #[cfg(test)]
 mod tests;
+pub mod upgrade;
 pub mod weights;
 
 pub use pallet::*;
@@ -40,6 +45,12 @@ pub mod weight_info {
         // bundle.
         fn publish_module_generic(gas: u32) -> Weight;
         fn update_stdlib_bundle() -> Weight;
+        fn set_upgrade_policy() -> Weight;
+        fn add_reserved_address() -> Weight;
+        fn remove_reserved_address() -> Weight;
+        fn publish_framework_bundle(modules: u32) -> Weight;
+        fn cancel_multisig_request(s: u32) -> Weight;
+        fn revoke_multisig_signature() -> Weight;
     }
 }
 
@@ -55,45 +66,70 @@ pub mod pallet {
 
     use codec::{FullCodec, FullEncode};
     use frame_support::{
-        dispatch::DispatchResultWithPostInfo,
+        dispatch::{DispatchResultWithPostInfo, PostDispatchInfo},
         pallet_prelude::*,
         parameter_types,
+        storage::{with_transaction, TransactionOutcome},
         traits::{
-            tokens::currency::LockIdentifier, Currency, Get, LockableCurrency, ReservableCurrency,
+            tokens::currency::LockIdentifier, Currency, ExistenceRequirement, Get, Imbalance,
+            LockableCurrency, OnUnbalanced, PalletInfoAccess, ReservableCurrency, WithdrawReasons,
         },
         BoundedBTreeSet,
     };
     use frame_system::pallet_prelude::*;
     pub use move_core_types::language_storage::TypeTag;
-    use move_core_types::{account_address::AccountAddress, language_storage::CORE_CODE_ADDRESS};
+    use move_core_types::{
+        account_address::AccountAddress,
+        identifier::{IdentStr, Identifier},
+        language_storage::{ModuleId, StructTag, CORE_CODE_ADDRESS},
+        vm_status::StatusCode,
+    };
     pub use move_vm_backend::types::{GasAmount, GasStrategy};
     use move_vm_backend::{
         balance::BalanceHandler, genesis::VmGenesisConfig, types::VmResult, Mvm,
     };
     use move_vm_backend_common::abi::ModuleAbi;
     pub use move_vm_backend_common::{
-        bytecode::verify_script_integrity_and_check_signers, types::ScriptTransaction,
+        bytecode::verify_script_integrity_and_check_signers,
+        types::{ModuleBundle, ScriptTransaction},
     };
     use sp_core::crypto::AccountId32;
-    use sp_runtime::traits::{AtLeast32BitUnsigned, One};
+    use sp_runtime::{
+        traits::{AtLeast32BitUnsigned, One, Saturating},
+        ModuleError, SaturatedConversion,
+    };
     use sp_std::{vec, vec::Vec};
 
     use super::*;
     use crate::{
-        api::MoveApiEstimation,
-        balance::{BalanceAdapter, BalanceOf},
+        api::{
+            MoveApiChequeLimits, MoveApiEstimation, MoveApiModuleInfo, MoveApiMultisigRequest,
+            MoveApiMultisigSigner, MoveApiSimulation, MoveApiStorageChange, MoveApiValue,
+            MoveApiWriteOp,
+        },
+        balance::{BalanceAdapter, BalanceOf, NegativeImbalanceOf},
+        deposit::StorageDeposit,
         signer::*,
-        storage::{MoveVmStorage, StorageAdapter},
+        storage::{
+            MoveVmStorage, OriginalValues, StorageAdapter, StorageEntry, StorageKeyIndex,
+            StorageUsage, StorageUsageDelta, CODE_TAG, RESOURCE_TAG,
+        },
+        upgrade::UpgradePolicy,
         weight_info::WeightInfo,
     };
 
-    type MvmResult<T> = Result<Mvm<StorageAdapter<VMStorage<T>>, BalanceAdapter<T>>, Vec<u8>>;
+    type VmStorageAdapter<T> = StorageAdapter<VMStorage<T>, Vec<u8>, Vec<u8>, VMStorageKeys<T>>;
+    type MvmResult<T> = Result<Mvm<VmStorageAdapter<T>, BalanceAdapter<T>>, Vec<u8>>;
 
     parameter_types! {
         pub const MaxChoreEntriesPerVec: u32 = 128;
     }
 
+    /// The current storage version.
+    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info] // Allows to define storage items without fixed size
     pub struct Pallet<T>(_);
 
@@ -103,10 +139,21 @@ pub mod pallet {
     #[pallet::storage]
     pub type VMStorage<T> = StorageMap<_, Blake2_128Concat, Vec<u8>, Vec<u8>>;
 
+    /// Index of the keys in `VMStorage` per owner's address and tag of the access path, to look up
+    /// the modules or resources of a single account.
+    #[pallet::storage]
+    pub type VMStorageKeys<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, (T::AccountId, u8), Blake2_128Concat, Vec<u8>, ()>;
+
     /// Storage for multi-signature/signer requests.
     #[pallet::storage]
     pub type MultisigStorage<T> = StorageMap<_, Blake2_128Concat, CallHash, SigDataOf<T>>;
 
+    /// Index of the pending multi-signer requests in `MultisigStorage` per signer.
+    #[pallet::storage]
+    pub type MultisigRequestsBySigner<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, CallHash, ()>;
+
     #[pallet::storage]
     pub type ChoreOnIdleStorage<T> = StorageMap<
         _,
@@ -118,6 +165,36 @@ pub mod pallet {
     #[pallet::storage]
     pub type ChoreOnIdleIndex<T> = StorageValue<_, BlockNumberFor<T>>;
 
+    /// Upgrade policies of published modules, stored per publisher and module name.
+    #[pallet::storage]
+    pub type ModuleUpgradePolicy<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        Vec<u8>,
+        UpgradePolicy,
+        ValueQuery,
+    >;
+
+    /// Reserved addresses, where only the `ReservedAddressOrigin` can publish modules, e.g. for
+    /// chain-specific frameworks next to the standard library.
+    #[pallet::storage]
+    pub type ReservedAddresses<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+    /// Storage usage and reserved storage deposits of accounts holding data in the MoveVM storage,
+    /// keyed by the owner of the data and the account which paid the deposit for it.
+    #[pallet::storage]
+    pub type StorageDeposits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        StorageDeposit<BalanceOf<T>>,
+        ValueQuery,
+    >;
+
     /// MoveVM pallet configuration trait
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -147,6 +224,27 @@ pub mod pallet {
         #[pallet::constant]
         type MaxScriptSigners: Get<u32>;
 
+        /// Deposit reserved for each byte held in the MoveVM storage.
+        #[pallet::constant]
+        type DepositPerByte: Get<BalanceOf<Self>>;
+
+        /// Deposit reserved for each item (module or resource) held in the MoveVM storage.
+        #[pallet::constant]
+        type DepositPerItem: Get<BalanceOf<Self>>;
+
+        /// Price of a single gas unit, charged on top of the weight-based transaction fee.
+        #[pallet::constant]
+        type GasPrice: Get<BalanceOf<Self>>;
+
+        /// Handler for the charged gas fees, e.g. a treasury. Use `()` to burn them.
+        type OnGasFee: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// Origin allowed to update the standard library bundles, e.g. root or a council.
+        type StdlibUpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to manage reserved addresses and to publish modules to them.
+        type ReservedAddressOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         /// Because this pallet emits events, it depends on the runtime's definition of an event.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -164,18 +262,40 @@ pub mod pallet {
         /// Event about calling execute function.
         /// [account]
         ExecuteCalled { who: Vec<T::AccountId> },
+        /// Event about successful framework bundle publishing at a reserved address.
+        /// [account]
+        FrameworkPublished { address: T::AccountId },
         /// Event about successful move-module publishing.
         /// [account]
         ModulePublished { who: T::AccountId },
+        /// Event about a multi-signing request cancelled by one of its signers.
+        /// [account, call]
+        MultiSignRequestCancelled { who: T::AccountId, call: CallHash },
         /// Event about removed multi-signing request.
         /// [vec<account>]
         MultiSignRequestRemoved { call: Vec<CallHash> },
+        /// Event about a revoked signature for a multi-signer execution request.
+        /// [account, call]
+        MultisigSignatureRevoked { who: T::AccountId, call: CallHash },
+        /// Event about a newly reserved address.
+        /// [account]
+        ReservedAddressAdded { address: T::AccountId },
+        /// Event about a released reserved address.
+        /// [account]
+        ReservedAddressRemoved { address: T::AccountId },
         /// Event about another signature for a multi-signer execution request.
         /// [account, multisignstate]
         SignedMultisigScript { who: T::AccountId },
         /// Event about successful stdlib update executed
         /// No parameters.
         StdlibUpdated,
+        /// Event about a changed upgrade policy of a published module.
+        /// [account, module, policy]
+        UpgradePolicyChanged {
+            who: T::AccountId,
+            module: Vec<u8>,
+            policy: UpgradePolicy,
+        },
     }
 
     #[pallet::genesis_config]
@@ -268,86 +388,20 @@ pub mod pallet {
             let args: Vec<&[u8]> = args.iter().map(AsRef::as_ref).collect();
 
             // Make sure the scripts are not maliciously trying to use forged signatures.
-            let signer_count =
-                verify_script_integrity_and_check_signers(&bytecode).map_err(Error::<T>::from)?;
+            let signer_count = verify_script_integrity_and_check_signers(&bytecode)
+                .map_err(result::vm_error::<T>)?;
             let unique_signers = Self::extract_account_ids_from_args(&args, signer_count)?;
 
-            // Based on the number of unique signers, decide the following:
-            let (is_signature_required, contains_multisig) = match unique_signers.len() {
-                0 => (false, None),
-                1 => (true, None),
-                _ => (
-                    true,
-                    // Generate the unique script tx hash to identify this multi-sign call request.
-                    Some(Self::transaction_bc_call_hash(&transaction_bc[..])),
-                ),
-            };
-
-            let mut signature_handler = if let Some(script_hash) = contains_multisig {
-                let multisig_data = MultisigStorage::<T>::get(script_hash).unwrap_or(
-                    SigDataOf::<T>::new(unique_signers).map_err(Into::<Error<T>>::into)?,
-                );
-
-                ScriptSignatureHandler::<T>::from(multisig_data)
-            } else {
-                ScriptSignatureHandler::<T>::new(unique_signers)?
-            };
-
-            if is_signature_required {
-                let lock_id = Self::multi_signer_lock_id(&who, &transaction_bc[..], &cheque_limit);
-                signature_handler.sign_script(&who, &cheque_limit, lock_id)?;
-            }
-
-            // The script needs to be signed by all signers before we can execute it in MoveVM and
-            // update the blockchain storage or the balance sheet.
-            if !signature_handler.all_signers_approved() {
-                // We can enter this block only in multisig scenario, so unwrap can't fail here.
-                let script_hash = contains_multisig.expect("multisig script hash not found");
-                let mut sig_data = signature_handler.into_inner();
-
-                // The deadline for collecting all signatures is set by the first signer in the
-                // multisig scenario. There's no way to extend the initally set deadline.
-                if sig_data.stored_block_height().is_none() {
-                    let block_height = <frame_system::Pallet<T>>::block_number();
-
-                    sig_data.set_block_height(block_height);
-                    Self::new_multi_sign_request_chore(block_height, script_hash)?;
-                }
-
-                MultisigStorage::<T>::insert(script_hash, sig_data);
-
-                Self::deposit_event(Event::SignedMultisigScript { who });
-                return result::execute_only_signing();
-            }
-
-            // If we have multiple signers and they all have signed, we have to remove the multi-signer request from the MultisigStorage.
-            if let Some(script_hash) = contains_multisig {
-                MultisigStorage::<T>::remove(script_hash);
-            }
-
-            // We need to provide MoveVM read only access to balance sheet - MoveVM is allowed to
-            // update the cheques that are used afterwards to update the balances afterwards.
-            let balance = signature_handler.write_cheques()?;
-
-            // Let's try execute the script.
-            let cheques = balance.clone(); // VM can only touch the cheque list, it cannot update balances directly.
-            let vm_result = Self::raw_execute_script(&bytecode, type_args, args, gas, cheques)?;
-
-            // Apply true transactions to blockchain - this can be done only from the pallet layer
-            // after the script executed correctly without any issues.
-            balance.apply_transactions()?;
-
-            let result = result::from_vm_result::<T>(vm_result)?;
-
-            // Emit an event.
-            let mut signers = signature_handler.into_signer_accounts()?;
-            if signers.is_empty() {
-                // Signer list can be empty in zero-signer scripts, so append here the user at least.
-                signers.push(who);
-            }
-            Self::deposit_event(Event::ExecuteCalled { who: signers });
-
-            Ok(result)
+            Self::execute_signed(
+                who,
+                &transaction_bc[..],
+                unique_signers,
+                cheque_limit,
+                gas_limit,
+                |cheques, usage| {
+                    Self::raw_execute_script(&bytecode, type_args, args, gas, cheques, usage)
+                },
+            )
         }
 
         /// Publish a Move module sent by the user.
@@ -363,14 +417,25 @@ pub mod pallet {
             let who = ensure_signed(origin)?;
             let address = Self::to_move_address(&who)?;
 
-            let gas_amount =
-                GasAmount::new(gas_limit.into()).map_err(|_| Error::<T>::GasLimitExceeded)?;
-            let gas = GasStrategy::Metered(gas_amount);
+            let gas_fee = Self::withdraw_gas_fee(&who, gas_limit)?;
 
-            let vm_result = Self::raw_publish_module(&address, bytecode, gas)?;
+            let usage = StorageUsage::default();
+            let vm_result = Self::publish_and_init(
+                &address,
+                Self::new_init_modules(core::slice::from_ref(&bytecode))?,
+                Some(gas_limit),
+                usage.clone(),
+                |gas| Self::raw_publish_module(&address, bytecode.clone(), gas, usage.clone()),
+            )?;
 
             // Produce a result with gas spent.
-            let result = result::from_vm_result::<T>(vm_result)?;
+            let gas_used = vm_result.gas_used;
+            let result =
+                result::from_vm_result::<T>(vm_result, T::WeightInfo::publish_module_generic)?;
+
+            // The publisher is the only signer.
+            Self::settle_storage_deposits(&usage, &[who.clone()], &who)?;
+            Self::settle_gas_fee(&who, gas_fee, gas_used);
 
             // Emit an event.
             Self::deposit_event(Event::ModulePublished { who });
@@ -391,14 +456,25 @@ pub mod pallet {
             let who = ensure_signed(origin)?;
             let address = Self::to_move_address(&who)?;
 
-            let gas_amount =
-                GasAmount::new(gas_limit.into()).map_err(|_| Error::<T>::GasLimitExceeded)?;
-            let gas = GasStrategy::Metered(gas_amount);
+            let gas_fee = Self::withdraw_gas_fee(&who, gas_limit)?;
 
-            let vm_result = Self::raw_publish_bundle(&address, bundle, gas)?;
+            let usage = StorageUsage::default();
+            let vm_result = Self::publish_and_init(
+                &address,
+                Self::new_init_modules(&Self::bundle_modules(&bundle))?,
+                Some(gas_limit),
+                usage.clone(),
+                |gas| Self::raw_publish_bundle(&address, bundle.clone(), gas, usage.clone()),
+            )?;
 
             // Produce a result with gas spent.
-            let result = result::from_vm_result::<T>(vm_result)?;
+            let gas_used = vm_result.gas_used;
+            let result =
+                result::from_vm_result::<T>(vm_result, T::WeightInfo::publish_module_generic)?;
+
+            // The publisher is the only signer.
+            Self::settle_storage_deposits(&usage, &[who.clone()], &who)?;
+            Self::settle_gas_fee(&who, gas_fee, gas_used);
 
             // Emit an event.
             Self::deposit_event(Event::BundlePublished { who });
@@ -406,7 +482,8 @@ pub mod pallet {
             Ok(result)
         }
 
-        /// Publish a standard library bundle, e.g. Move-Stdlib or Substrate-Stdlib. Sudo user only.
+        /// Publish a standard library bundle, e.g. Move-Stdlib or Substrate-Stdlib. Only callable
+        /// by the `StdlibUpdateOrigin`.
         ///
         /// All standard libraries are published at their default address 0x1.
         #[pallet::call_index(3)]
@@ -415,16 +492,377 @@ pub mod pallet {
             origin: OriginFor<T>,
             stdlib: Vec<u8>,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
-
-            let vm_result =
-                Self::raw_publish_bundle(&CORE_CODE_ADDRESS, stdlib, GasStrategy::Unmetered)?;
-            let pd_info = result::from_vm_result::<T>(vm_result)?;
+            T::StdlibUpdateOrigin::ensure_origin(origin)?;
+
+            // Standard libraries don't require any storage deposit.
+            let vm_result = Self::raw_publish_bundle(
+                &CORE_CODE_ADDRESS,
+                stdlib,
+                GasStrategy::Unmetered,
+                StorageUsage::default(),
+            )?;
+            // The standard library is published without any gas metering.
+            let pd_info =
+                result::from_vm_result::<T>(vm_result, |_| T::WeightInfo::update_stdlib_bundle())?;
 
             Self::deposit_event(Event::<T>::StdlibUpdated);
 
             Ok(pd_info)
         }
+
+        /// Execute an entry function of an already published Move module.
+        ///
+        /// The function is addressed by the module's address, the module name and the function
+        /// name. Type arguments are BCS-encoded `TypeTag`s and arguments are BCS-encoded values,
+        /// in the same way as in a script transaction. Signers are handled like in `execute`.
+        // Calling an entry function shares the weight cost calculation with the script execution.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::execute(*gas_limit))]
+        #[allow(clippy::too_many_arguments)]
+        pub fn execute_entry_function(
+            origin: OriginFor<T>,
+            module_address: T::AccountId,
+            module_name: Vec<u8>,
+            function_name: Vec<u8>,
+            type_args: Vec<Vec<u8>>,
+            args: Vec<Vec<u8>>,
+            gas_limit: u32,
+            cheque_limit: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let gas_amount =
+                GasAmount::new(gas_limit.into()).map_err(|_| Error::<T>::GasLimitExceeded)?;
+            let gas = GasStrategy::Metered(gas_amount);
+
+            // The encoded call identifies a multi-signer request, like the script transaction
+            // does it in the `execute` extrinsic. It is tagged, so that both can't share a call
+            // hash.
+            let call_data = (
+                ENTRY_FUNCTION_CALL_TAG,
+                &module_address,
+                &module_name,
+                &function_name,
+                &type_args,
+                &args,
+            )
+                .encode();
+
+            let module_id = ModuleId::new(
+                Self::to_move_address_unchecked(&module_address)?,
+                Self::to_identifier(module_name)?,
+            );
+            let function = Self::to_identifier(function_name)?;
+            let type_args = type_args
+                .iter()
+                .map(|type_arg| bcs::from_bytes(type_arg))
+                .collect::<Result<Vec<TypeTag>, _>>()
+                .map_err(|_| Error::<T>::InvalidEntryFunctionCall)?;
+            let args: Vec<&[u8]> = args.iter().map(AsRef::as_ref).collect();
+
+            // Make sure nobody is trying to use forged signatures.
+            ensure!(
+                !bytecode::type_args_contain_signer(&type_args),
+                Error::<T>::ScriptSignatureFailure
+            );
+            let signer_count = Self::entry_function_signer_count(&module_id, &function)?;
+            let unique_signers = Self::extract_account_ids_from_args(&args, signer_count)?;
+
+            Self::execute_signed(
+                who,
+                &call_data[..],
+                unique_signers,
+                cheque_limit,
+                gas_limit,
+                |cheques, usage| {
+                    Self::raw_execute_function(
+                        &module_id, &function, type_args, args, gas, cheques, usage,
+                    )
+                },
+            )
+        }
+
+        /// Set the upgrade policy of a Move module published by the user.
+        ///
+        /// Policies can only be tightened, so an immutable module stays immutable forever.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::set_upgrade_policy())]
+        pub fn set_upgrade_policy(
+            origin: OriginFor<T>,
+            module_name: Vec<u8>,
+            policy: UpgradePolicy,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let name = core::str::from_utf8(&module_name)
+                .ok()
+                .filter(|name| Identifier::is_valid(name))
+                .ok_or(Error::<T>::InvalidModuleName)?;
+            let module = Self::get_module(&who, name).map_err(|_| Error::<T>::StorageError)?;
+            ensure!(module.is_some(), Error::<T>::ModuleNotFound);
+
+            ModuleUpgradePolicy::<T>::try_mutate(&who, &module_name, |current| {
+                ensure!(
+                    current.can_change_to(&policy),
+                    Error::<T>::UpgradePolicyNotAllowed
+                );
+                *current = policy;
+                Ok::<(), Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::UpgradePolicyChanged {
+                who,
+                module: module_name,
+                policy,
+            });
+
+            Ok(())
+        }
+
+        /// Reserve an address, so that only the `ReservedAddressOrigin` can publish modules there.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::add_reserved_address())]
+        pub fn add_reserved_address(origin: OriginFor<T>, address: T::AccountId) -> DispatchResult {
+            T::ReservedAddressOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !ReservedAddresses::<T>::contains_key(&address),
+                Error::<T>::AddressAlreadyReserved
+            );
+            // The standard library address is reserved anyway.
+            Self::to_move_address(&address)?;
+            // Otherwise, modules and resources of a regular user would end up in a framework.
+            ensure!(
+                [CODE_TAG, RESOURCE_TAG]
+                    .into_iter()
+                    .all(|tag| !VMStorageKeys::<T>::contains_prefix((address.clone(), tag))),
+                Error::<T>::AddressNotEmpty
+            );
+
+            ReservedAddresses::<T>::insert(&address, ());
+            Self::deposit_event(Event::ReservedAddressAdded { address });
+
+            Ok(())
+        }
+
+        /// Release a reserved address, so that it can be used like any other address again.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::remove_reserved_address())]
+        pub fn remove_reserved_address(
+            origin: OriginFor<T>,
+            address: T::AccountId,
+        ) -> DispatchResult {
+            T::ReservedAddressOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                ReservedAddresses::<T>::take(&address).is_some(),
+                Error::<T>::AddressNotReserved
+            );
+            Self::deposit_event(Event::ReservedAddressRemoved { address });
+
+            Ok(())
+        }
+
+        /// Publish a bundle at a reserved address, e.g. a chain-specific framework. Only callable
+        /// by the `ReservedAddressOrigin`.
+        ///
+        /// The call is weighed for `module_count` modules, the bundle must not contain more.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::publish_framework_bundle(*module_count))]
+        pub fn publish_framework_bundle(
+            origin: OriginFor<T>,
+            address: T::AccountId,
+            bundle: Vec<u8>,
+            module_count: u32,
+        ) -> DispatchResultWithPostInfo {
+            T::ReservedAddressOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                ReservedAddresses::<T>::contains_key(&address),
+                Error::<T>::AddressNotReserved
+            );
+            let move_address = Self::to_move_address_unchecked(&address)?;
+            let module_count = {
+                let actual_count = Self::bundle_modules(&bundle).len() as u32;
+                ensure!(
+                    actual_count <= module_count,
+                    Error::<T>::ModuleCountExceeded
+                );
+                actual_count
+            };
+
+            // Like the standard library, frameworks don't require any storage deposit.
+            let vm_result = Self::raw_publish_bundle(
+                &move_address,
+                bundle,
+                GasStrategy::Unmetered,
+                StorageUsage::default(),
+            )?;
+            // Frameworks are published without any gas metering.
+            let pd_info = result::from_vm_result::<T>(vm_result, |_| {
+                T::WeightInfo::publish_framework_bundle(module_count)
+            })?;
+
+            Self::deposit_event(Event::<T>::FrameworkPublished { address });
+
+            Ok(pd_info)
+        }
+
+        /// Publish a Move bundle and execute an initialization script within a single atomic
+        /// call. If the script fails, the publication gets reverted as well.
+        ///
+        /// The script can only be signed by the publisher. The `gas_limit` covers both the
+        /// publication and the script execution.
+        #[pallet::call_index(9)]
+        #[pallet::weight(
+            T::WeightInfo::publish_module_generic(*gas_limit)
+                .saturating_add(T::WeightInfo::execute(*gas_limit))
+        )]
+        pub fn publish_bundle_and_execute(
+            origin: OriginFor<T>,
+            bundle: Vec<u8>,
+            transaction_bc: Vec<u8>,
+            gas_limit: u32,
+            cheque_limit: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let address = Self::to_move_address(&who)?;
+
+            let ScriptTransaction {
+                bytecode,
+                args,
+                type_args,
+            } = ScriptTransaction::try_from(transaction_bc.as_ref())
+                .map_err(|_| Error::<T>::InvalidScriptTransaction)?;
+            let args: Vec<&[u8]> = args.iter().map(AsRef::as_ref).collect();
+
+            // Multi-signer requests can't be collected within a single call, so only the
+            // publisher is allowed to sign the script.
+            let signer_count = verify_script_integrity_and_check_signers(&bytecode)
+                .map_err(result::vm_error::<T>)?;
+            let unique_signers = Self::extract_account_ids_from_args(&args, signer_count)?;
+            ensure!(
+                unique_signers.iter().all(|signer| signer == &who),
+                Error::<T>::UnexpectedUserSignature
+            );
+
+            let mut balance = BalanceAdapter::<T>::new();
+            if !unique_signers.is_empty() {
+                balance.write_cheque(&who, &cheque_limit)?;
+            }
+
+            let gas_fee = Self::withdraw_gas_fee(&who, gas_limit)?;
+            let usage = StorageUsage::default();
+
+            let vm_result = Self::publish_and_init(
+                &address,
+                Self::new_init_modules(&Self::bundle_modules(&bundle))?,
+                Some(gas_limit),
+                usage.clone(),
+                |gas| Self::raw_publish_bundle(&address, bundle.clone(), gas, usage.clone()),
+            )?;
+            let publish_gas_used = vm_result.gas_used;
+            let publish_result =
+                result::from_vm_result::<T>(vm_result, T::WeightInfo::publish_module_generic)?;
+            let publish_weight = publish_result.actual_weight.unwrap_or_default();
+
+            // The script can use the remaining gas.
+            let gas = Self::gas_strategy(Some(gas_limit), publish_gas_used)?;
+            let vm_result = Self::raw_execute_script(
+                &bytecode,
+                type_args,
+                args,
+                gas,
+                balance.clone(),
+                usage.clone(),
+            )?;
+            balance.apply_transactions()?;
+
+            let gas_used = publish_gas_used.saturating_add(vm_result.gas_used);
+            // The weight of both parts is charged, even if the script fails.
+            let add_publish_weight = |mut post_info: PostDispatchInfo| {
+                post_info.actual_weight = post_info
+                    .actual_weight
+                    .map(|weight| weight.saturating_add(publish_weight));
+                post_info
+            };
+            let result = result::from_vm_result::<T>(vm_result, T::WeightInfo::execute)
+                .map(add_publish_weight)
+                .map_err(|mut error| {
+                    error.post_info = add_publish_weight(error.post_info);
+                    error
+                })?;
+
+            Self::settle_storage_deposits(&usage, &[who.clone()], &who)?;
+            Self::settle_gas_fee(&who, gas_fee, gas_used);
+
+            Self::deposit_event(Event::BundlePublished { who: who.clone() });
+            Self::deposit_event(Event::ExecuteCalled { who: vec![who] });
+
+            Ok(result)
+        }
+
+        /// Cancel a pending multi-signer execution request.
+        ///
+        /// Any signer of the request can cancel it. The funds locked by all signers who have
+        /// already signed the request get unlocked again.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::cancel_multisig_request(T::MaxScriptSigners::get()))]
+        pub fn cancel_multisig_request(
+            origin: OriginFor<T>,
+            call_hash: CallHash,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let sig_data =
+                MultisigStorage::<T>::get(call_hash).ok_or(Error::<T>::MultisigRequestNotFound)?;
+            ensure!(
+                sig_data.contains_key(&who),
+                Error::<T>::UnexpectedUserSignature
+            );
+
+            // The pre-dispatch weight assumes the maximum number of signers.
+            let signer_count = sig_data.len() as u32;
+
+            if let Some(block_height) = sig_data.stored_block_height() {
+                Self::remove_multi_sign_request_chore(*block_height, &call_hash);
+            }
+            ScriptSignatureHandler::<T>::from(sig_data).release_locks();
+            Self::take_multisig_request(&call_hash);
+
+            Self::deposit_event(Event::MultiSignRequestCancelled {
+                who,
+                call: call_hash,
+            });
+
+            Ok(Some(T::WeightInfo::cancel_multisig_request(signer_count)).into())
+        }
+
+        /// Revoke the own signature of a pending multi-signer execution request.
+        ///
+        /// The signer's funds get unlocked again, while the request stays pending until it gets
+        /// signed again, cancelled or it expires.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::revoke_multisig_signature())]
+        pub fn revoke_multisig_signature(
+            origin: OriginFor<T>,
+            call_hash: CallHash,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let sig_data =
+                MultisigStorage::<T>::get(call_hash).ok_or(Error::<T>::MultisigRequestNotFound)?;
+            let mut signature_handler = ScriptSignatureHandler::<T>::from(sig_data);
+            signature_handler.revoke_signature(&who)?;
+            MultisigStorage::<T>::insert(call_hash, signature_handler.into_inner());
+
+            Self::deposit_event(Event::MultisigSignatureRevoked {
+                who,
+                call: call_hash,
+            });
+
+            Ok(())
+        }
     }
 
     /// Prepare a storage adapter ready for the Virtual Machine.
@@ -435,6 +873,21 @@ pub mod pallet {
         V: FullCodec,
     {
         type VmStorage = VMStorage<T>;
+        type VmStorageIndex = VMStorageKeys<T>;
+    }
+
+    impl<T: Config> StorageKeyIndex for VMStorageKeys<T> {
+        fn insert(owner: &AccountAddress, tag: u8, path: &[u8]) {
+            if let Ok(owner) = Pallet::<T>::to_native_account(owner) {
+                VMStorageKeys::<T>::insert((owner, tag), path, ());
+            }
+        }
+
+        fn remove(owner: &AccountAddress, tag: u8, path: &[u8]) {
+            if let Ok(owner) = Pallet::<T>::to_native_account(owner) {
+                VMStorageKeys::<T>::remove((owner, tag), path);
+            }
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -453,18 +906,136 @@ pub mod pallet {
             T::AccountId::decode(&mut address.as_ref()).map_err(|_| Error::InvalidAccountSize)
         }
 
-        /// Convert a native address to a Move address.
+        /// Convert a native address to a Move address, where regular users can publish modules.
+        ///
+        /// The standard library address and the reserved addresses are rejected.
         pub fn to_move_address(address: &T::AccountId) -> Result<AccountAddress, Error<T>> {
+            let move_address = Self::to_move_address_unchecked(address)?;
+
+            ensure!(
+                move_address != CORE_CODE_ADDRESS,
+                Error::<T>::StdlibAddressNotAllowed
+            );
+            ensure!(
+                !ReservedAddresses::<T>::contains_key(address),
+                Error::<T>::ReservedAddressNotAllowed
+            );
+
+            Ok(move_address)
+        }
+
+        /// Convert a native address to any Move address, including the protected ones, e.g. for
+        /// reading from the storage.
+        pub fn to_move_address_unchecked(
+            address: &T::AccountId,
+        ) -> Result<AccountAddress, Error<T>> {
             let address = AccountId32::decode(&mut address.encode().as_ref())
                 .map_err(|_| Error::InvalidAccountSize)?;
 
             let account_bytes: [u8; 32] = address.into();
-            let address = AccountAddress::new(account_bytes);
-            if address == CORE_CODE_ADDRESS {
-                Err(Error::<T>::StdlibAddressNotAllowed)
-            } else {
-                Ok(address)
+            Ok(AccountAddress::new(account_bytes))
+        }
+
+        /// Collects the signature of `who` and executes the call once all signers have signed.
+        ///
+        /// The `call_data` uniquely identifies the call for the multi-signer request handling,
+        /// while `execute_call` runs the call within the MoveVM with the cheques of all signers.
+        fn execute_signed(
+            who: T::AccountId,
+            call_data: &[u8],
+            unique_signers: BoundedBTreeSet<T::AccountId, T::MaxScriptSigners>,
+            cheque_limit: BalanceOf<T>,
+            gas_limit: u32,
+            execute_call: impl FnOnce(BalanceAdapter<T>, StorageUsage) -> Result<VmResult, Error<T>>,
+        ) -> DispatchResultWithPostInfo {
+            // Based on the number of unique signers, decide the following:
+            let (is_signature_required, contains_multisig) = match unique_signers.len() {
+                0 => (false, None),
+                1 => (true, None),
+                _ => (
+                    true,
+                    // Generate the unique call hash to identify this multi-sign call request.
+                    Some(Self::transaction_bc_call_hash(call_data)),
+                ),
+            };
+
+            let mut signature_handler = if let Some(script_hash) = contains_multisig {
+                let multisig_data = MultisigStorage::<T>::get(script_hash).unwrap_or(
+                    SigDataOf::<T>::new(unique_signers).map_err(Into::<Error<T>>::into)?,
+                );
+
+                ScriptSignatureHandler::<T>::from(multisig_data)
+            } else {
+                ScriptSignatureHandler::<T>::new(unique_signers)?
+            };
+
+            if is_signature_required {
+                let lock_id = Self::multi_signer_lock_id(&who, call_data, &cheque_limit);
+                signature_handler.sign_script(&who, &cheque_limit, lock_id)?;
             }
+
+            // The script needs to be signed by all signers before we can execute it in MoveVM and
+            // update the blockchain storage or the balance sheet.
+            if !signature_handler.all_signers_approved() {
+                // We can enter this block only in multisig scenario, so unwrap can't fail here.
+                let script_hash = contains_multisig.expect("multisig script hash not found");
+                let mut sig_data = signature_handler.into_inner();
+
+                // The deadline for collecting all signatures is set by the first signer in the
+                // multisig scenario. There's no way to extend the initally set deadline.
+                if sig_data.stored_block_height().is_none() {
+                    let block_height = <frame_system::Pallet<T>>::block_number();
+
+                    sig_data.set_block_height(block_height);
+                    Self::new_multi_sign_request_chore(block_height, script_hash)?;
+
+                    for signer in sig_data.keys() {
+                        MultisigRequestsBySigner::<T>::insert(signer, script_hash, ());
+                    }
+                }
+
+                MultisigStorage::<T>::insert(script_hash, sig_data);
+
+                Self::deposit_event(Event::SignedMultisigScript { who });
+                return result::execute_only_signing();
+            }
+
+            // If we have multiple signers and they all have signed, we have to remove the multi-signer request from the MultisigStorage.
+            if let Some(script_hash) = contains_multisig {
+                Self::take_multisig_request(&script_hash);
+            }
+
+            // We need to provide MoveVM read only access to balance sheet - MoveVM is allowed to
+            // update the cheques that are used afterwards to update the balances afterwards.
+            let balance = signature_handler.write_cheques()?;
+
+            // The user who triggers the execution pays for the gas.
+            let gas_fee = Self::withdraw_gas_fee(&who, gas_limit)?;
+
+            // Let's try execute the call.
+            let cheques = balance.clone(); // VM can only touch the cheque list, it cannot update balances directly.
+            let usage = StorageUsage::default();
+            let vm_result = execute_call(cheques, usage.clone())?;
+
+            // Apply true transactions to blockchain - this can be done only from the pallet layer
+            // after the script executed correctly without any issues.
+            balance.apply_transactions()?;
+
+            let gas_used = vm_result.gas_used;
+            let result = result::from_vm_result::<T>(vm_result, T::WeightInfo::execute)?;
+
+            let mut signers = signature_handler.into_signer_accounts()?;
+            Self::settle_storage_deposits(&usage, &signers, &who)?;
+            Self::settle_gas_fee(&who, gas_fee, gas_used);
+
+            // Emit an event.
+            if signers.is_empty() {
+                // Signer list can be empty in zero-signer scripts, so append here the user at least.
+                signers.push(who);
+            }
+            Self::deposit_event(Event::ExecuteCalled { who: signers });
+
+            Ok(result)
         }
 
         /// Execute the script using the appropriate gas strategy.
@@ -474,8 +1045,9 @@ pub mod pallet {
             args: Vec<&[u8]>,
             gas: GasStrategy,
             cheques: impl BalanceHandler,
+            usage: StorageUsage,
         ) -> Result<VmResult, Error<T>> {
-            let storage = Self::move_vm_storage();
+            let storage = VmStorageAdapter::<T>::with_usage(usage);
 
             let vm = Mvm::new(storage, cheques).map_err(|_| Error::<T>::ExecuteFailed)?;
 
@@ -484,13 +1056,36 @@ pub mod pallet {
             Ok(result)
         }
 
+        /// Execute an entry function using the appropriate gas strategy.
+        pub fn raw_execute_function(
+            module_id: &ModuleId,
+            function: &IdentStr,
+            type_args: Vec<TypeTag>,
+            args: Vec<&[u8]>,
+            gas: GasStrategy,
+            cheques: impl BalanceHandler,
+            usage: StorageUsage,
+        ) -> Result<VmResult, Error<T>> {
+            let storage = VmStorageAdapter::<T>::with_usage(usage);
+
+            let vm = Mvm::new(storage, cheques).map_err(|_| Error::<T>::ExecuteFailed)?;
+
+            let result =
+                vm.execute_function(module_id.clone(), function.to_owned(), type_args, args, gas);
+
+            Ok(result)
+        }
+
         /// Publish the module using the appropriate gas strategy.
         pub fn raw_publish_module(
             address: &AccountAddress,
             bytecode: Vec<u8>,
             gas: GasStrategy,
+            usage: StorageUsage,
         ) -> Result<VmResult, Error<T>> {
-            let storage = Self::move_vm_storage();
+            Self::ensure_modules_upgradable(address, core::slice::from_ref(&bytecode))?;
+
+            let storage = VmStorageAdapter::<T>::with_usage(usage);
 
             let vm = Mvm::new(storage, BalanceAdapter::<T>::new())
                 .map_err(|_| Error::<T>::PublishModuleFailed)?;
@@ -505,8 +1100,11 @@ pub mod pallet {
             address: &AccountAddress,
             bundle: Vec<u8>,
             gas: GasStrategy,
+            usage: StorageUsage,
         ) -> Result<VmResult, Error<T>> {
-            let storage = Self::move_vm_storage();
+            Self::ensure_modules_upgradable(address, &Self::bundle_modules(&bundle))?;
+
+            let storage = VmStorageAdapter::<T>::with_usage(usage);
 
             let vm = Mvm::new(storage, BalanceAdapter::<T>::new())
                 .map_err(|_| Error::<T>::PublishBundleFailed)?;
@@ -516,13 +1114,37 @@ pub mod pallet {
             Ok(result)
         }
 
+        /// Execute the `init_module` function of a newly published module with the publisher as
+        /// the signer.
+        ///
+        /// The function is private, so users can't call it. The MoveVM backend's
+        /// `execute_function` doesn't check the visibility of the called function, the pallet does
+        /// that itself for `execute_entry_function`.
+        pub(crate) fn raw_init_module(
+            module_id: &ModuleId,
+            address: &AccountAddress,
+            gas: GasStrategy,
+            usage: StorageUsage,
+        ) -> Result<VmResult, Error<T>> {
+            // A signer is passed as its BCS-encoded address, which are just its raw bytes.
+            Self::raw_execute_function(
+                module_id,
+                bytecode::INIT_MODULE,
+                vec![],
+                vec![address.as_ref()],
+                gas,
+                BalanceAdapter::<T>::new(),
+                usage,
+            )
+        }
+
         pub fn get_module_abi(
             address: &T::AccountId,
             name: &str,
         ) -> Result<Option<ModuleAbi>, Vec<u8>> {
             let vm = Self::move_vm()?;
 
-            let address = Self::to_move_address(address).map_err(|_| vec![])?;
+            let address = Self::to_move_address_unchecked(address).map_err(|_| vec![])?;
 
             vm.get_module_abi(address, name)
                 .map_err(|e| format!("error in get_module_abi: {e:?}").into())
@@ -531,7 +1153,7 @@ pub mod pallet {
         pub fn get_module(address: &T::AccountId, name: &str) -> Result<Option<Vec<u8>>, Vec<u8>> {
             let vm = Self::move_vm()?;
 
-            let address = Self::to_move_address(address).map_err(|_| vec![])?;
+            let address = Self::to_move_address_unchecked(address).map_err(|_| vec![])?;
 
             vm.get_module(address, name)
                 .map_err(|e| format!("error in get_module: {e:?}").into())
@@ -542,12 +1164,335 @@ pub mod pallet {
             tag: &[u8],
         ) -> Result<Option<Vec<u8>>, Vec<u8>> {
             let vm = Self::move_vm()?;
-            let address = Self::to_move_address(account).map_err(|_| vec![])?;
+            let address = Self::to_move_address_unchecked(account).map_err(|_| vec![])?;
 
             vm.get_resource(&address, tag)
                 .map_err(|e| format!("error in get_resource: {e:?}").into())
         }
 
+        /// Gets a resource decoded with the layout of its struct, which gets resolved from the
+        /// published modules.
+        pub fn get_resource_value(
+            account: &T::AccountId,
+            tag: &[u8],
+        ) -> Result<Option<MoveApiValue>, Vec<u8>> {
+            let Some(resource) = Self::get_resource(account, tag)? else {
+                return Ok(None);
+            };
+
+            let tag: StructTag =
+                bcs::from_bytes(tag).map_err(|e| format!("invalid struct tag: {e:?}"))?;
+            let vm = Self::move_vm()?;
+            let layout = layout::struct_layout(&tag, &|module_id| {
+                vm.get_module(*module_id.address(), module_id.name().as_str())
+                    .ok()
+                    .flatten()
+            })?;
+
+            layout
+                .decode(&resource)
+                .map(Some)
+                .ok_or_else(|| format!("resource doesn't match the layout of {tag}").into())
+        }
+
+        /// Lists the BCS-encoded type tags of all resources stored under the account.
+        ///
+        /// The tags are listed in the stable, but arbitrary order of their storage keys. For
+        /// pagination, the listing starts after the tag `start` and returns at most `limit` tags.
+        pub fn list_resources(
+            account: &T::AccountId,
+            start: Option<Vec<u8>>,
+            limit: Option<u32>,
+        ) -> Result<Vec<Vec<u8>>, Vec<u8>> {
+            let prefix = (account.clone(), RESOURCE_TAG);
+            let tags = match start {
+                Some(start) => {
+                    let start = VMStorageKeys::<T>::hashed_key_for(&prefix, start);
+                    VMStorageKeys::<T>::iter_key_prefix_from(prefix, start)
+                }
+                None => VMStorageKeys::<T>::iter_key_prefix(prefix),
+            };
+            let limit = limit.map_or(usize::MAX, |limit| limit as usize);
+
+            Ok(tags.take(limit).collect())
+        }
+
+        /// Lists all modules published under the account, sorted by their names.
+        ///
+        /// The Blake2s-256 hashes of the module bytecodes are only calculated if `with_hashes` is
+        /// set.
+        pub fn list_modules(
+            account: &T::AccountId,
+            with_hashes: bool,
+        ) -> Result<Vec<MoveApiModuleInfo>, Vec<u8>> {
+            // The bytecodes are only loaded for calculating their hashes.
+            let vm = with_hashes.then(Self::move_vm).transpose()?;
+
+            let mut modules = VMStorageKeys::<T>::iter_key_prefix((account.clone(), CODE_TAG))
+                .map(|path| {
+                    let Some(StorageEntry::Module(module_id)) =
+                        StorageEntry::from_path(CODE_TAG, &path)
+                    else {
+                        return Err(format!("invalid module path: {path:?}"));
+                    };
+                    let name = module_id.name().as_str();
+
+                    let bytecode_hash = match &vm {
+                        Some(vm) => {
+                            let bytecode = vm
+                                .get_module(*module_id.address(), name)
+                                .map_err(|e| format!("error in get_module: {e:?}"))?
+                                .ok_or_else(|| format!("module {module_id} not found"))?;
+                            let mut hasher = Blake2s256::new();
+                            hasher.update(&bytecode);
+                            Some(hasher.finalize().into())
+                        }
+                        None => None,
+                    };
+
+                    Ok(MoveApiModuleInfo {
+                        name: name.into(),
+                        bytecode_hash,
+                    })
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+            modules.sort_by(|a, b| a.name.cmp(&b.name));
+
+            Ok(modules)
+        }
+
+        // Returns the number of signers the entry function of a published module expects.
+        fn entry_function_signer_count(
+            module_id: &ModuleId,
+            function: &IdentStr,
+        ) -> Result<usize, Error<T>> {
+            let vm = Self::move_vm().map_err(|_| Error::<T>::ExecuteFailed)?;
+
+            let module = vm
+                .get_module(*module_id.address(), module_id.name().as_str())
+                .map_err(|_| Error::<T>::StorageError)?
+                .ok_or(Error::<T>::LinkerError)?;
+
+            bytecode::entry_function_signer_count(&module, function)
+        }
+
+        // Withdraws the fee for the whole gas limit from the user.
+        fn withdraw_gas_fee(
+            who: &T::AccountId,
+            gas_limit: u32,
+        ) -> Result<NegativeImbalanceOf<T>, Error<T>> {
+            let fee = T::GasPrice::get().saturating_mul(u128::from(gas_limit).into());
+
+            T::Currency::withdraw(
+                who,
+                fee,
+                WithdrawReasons::FEE,
+                ExistenceRequirement::KeepAlive,
+            )
+            .map_err(|_| Error::<T>::InsufficientBalanceForGasFee)
+        }
+
+        // Refunds the fee for the unused gas and hands over the fee for the used gas.
+        fn settle_gas_fee(who: &T::AccountId, gas_fee: NegativeImbalanceOf<T>, gas_used: u64) {
+            let used_fee = T::GasPrice::get().saturating_mul(u128::from(gas_used).into());
+            let (used, unused) = gas_fee.split(used_fee);
+
+            T::Currency::resolve_creating(who, unused);
+            T::OnGasFee::on_unbalanced(used);
+        }
+
+        // Reserves or unreserves the storage deposits for all storage usage changes. Only the
+        // signers pay for growing their own storage, growing the storage of any other account is
+        // covered by the caller who triggered it. Removed data releases the deposits paid for the
+        // owner's storage, the ones paid by other accounts first.
+        fn settle_storage_deposits(
+            usage: &StorageUsage,
+            signers: &[T::AccountId],
+            caller: &T::AccountId,
+        ) -> Result<(), Error<T>> {
+            for (address, delta) in usage.borrow().iter() {
+                // Standard libraries are maintained by the chain itself.
+                if *address == CORE_CODE_ADDRESS {
+                    continue;
+                }
+                let owner = Self::to_native_account(address)?;
+
+                let growth = StorageUsageDelta {
+                    bytes: delta.bytes.max(0),
+                    items: delta.items.max(0),
+                };
+                if growth != StorageUsageDelta::default() {
+                    let payer = if signers.contains(&owner) {
+                        &owner
+                    } else {
+                        caller
+                    };
+                    Self::settle_storage_deposit(&owner, payer, &growth)?;
+                }
+
+                let mut shrinkage = StorageUsageDelta {
+                    bytes: delta.bytes.min(0),
+                    items: delta.items.min(0),
+                };
+                let deposits = StorageDeposits::<T>::iter_prefix(&owner)
+                    .filter(|(payer, _)| *payer != owner)
+                    .chain(core::iter::once((
+                        owner.clone(),
+                        StorageDeposits::<T>::get(&owner, &owner),
+                    )));
+                let mut releases = Vec::new();
+                for (payer, deposit) in deposits {
+                    if shrinkage == StorageUsageDelta::default() {
+                        break;
+                    }
+                    // A payer can't be refunded for more than it has paid for.
+                    let covered = |usage: u64| -i64::try_from(usage).unwrap_or(i64::MAX);
+                    let release = StorageUsageDelta {
+                        bytes: shrinkage.bytes.max(covered(deposit.bytes)),
+                        items: shrinkage.items.max(covered(deposit.items)),
+                    };
+                    shrinkage.bytes -= release.bytes;
+                    shrinkage.items -= release.items;
+                    releases.push((payer, release));
+                }
+                for (payer, release) in releases.iter() {
+                    Self::settle_storage_deposit(&owner, payer, release)?;
+                }
+            }
+
+            Ok(())
+        }
+
+        // Applies a storage usage change to the deposit `payer` holds for the storage of `owner`
+        // and reserves or unreserves the difference to the required deposit.
+        fn settle_storage_deposit(
+            owner: &T::AccountId,
+            payer: &T::AccountId,
+            delta: &StorageUsageDelta,
+        ) -> Result<(), Error<T>> {
+            StorageDeposits::<T>::try_mutate_exists(owner, payer, |entry| {
+                let mut deposit = entry.take().unwrap_or_default();
+                deposit.apply(delta);
+
+                let required = deposit.required(T::DepositPerByte::get(), T::DepositPerItem::get());
+                if required > deposit.amount {
+                    // A changed deposit price shall never block the removal of data.
+                    if delta.bytes > 0 || delta.items > 0 {
+                        T::Currency::reserve(payer, required - deposit.amount)
+                            .map_err(|_| Error::<T>::InsufficientStorageDeposit)?;
+                        deposit.amount = required;
+                    }
+                } else {
+                    T::Currency::unreserve(payer, deposit.amount - required);
+                    deposit.amount = required;
+                }
+
+                if deposit != StorageDeposit::default() {
+                    *entry = Some(deposit);
+                }
+                Ok(())
+            })
+        }
+
+        // Ensures that none of the given modules has been made immutable by its publisher.
+        fn ensure_modules_upgradable(
+            address: &AccountAddress,
+            modules: &[Vec<u8>],
+        ) -> Result<(), Error<T>> {
+            let publisher = Self::to_native_account(address)?;
+
+            // Modules which cannot be deserialized will be rejected by the MoveVM anyway.
+            for name in modules.iter().filter_map(|m| bytecode::module_name(m)) {
+                let policy = ModuleUpgradePolicy::<T>::get(&publisher, name.as_str().as_bytes());
+                if policy == UpgradePolicy::Immutable {
+                    return Err(Error::<T>::ModuleImmutable);
+                }
+            }
+
+            Ok(())
+        }
+
+        // Returns the modules of the given bundle. An invalid bundle will be rejected by the
+        // MoveVM anyway, so it doesn't contain any modules here.
+        fn bundle_modules(bundle: &[u8]) -> Vec<Vec<u8>> {
+            ModuleBundle::try_from(bundle)
+                .map(ModuleBundle::into_inner)
+                .unwrap_or_default()
+        }
+
+        // Returns the IDs of the given modules, which declare an `init_module` function and
+        // haven't been published yet.
+        fn new_init_modules(modules: &[Vec<u8>]) -> Result<Vec<ModuleId>, Error<T>> {
+            let vm = Self::move_vm().map_err(|_| Error::<T>::PublishModuleFailed)?;
+
+            let mut new_modules = Vec::new();
+            for module_id in modules.iter().filter_map(|m| bytecode::init_module_id(m)) {
+                let published = vm
+                    .get_module(*module_id.address(), module_id.name().as_str())
+                    .map_err(|_| Error::<T>::StorageError)?;
+                if published.is_none() {
+                    new_modules.push(module_id);
+                }
+            }
+
+            Ok(new_modules)
+        }
+
+        // Publishes the modules via `publish` and executes the `init_module` functions of the
+        // given new modules afterwards. The gas used by all parts is summed up, and the execution
+        // stops at the first failure. Without a gas limit, everything is executed as a dry run,
+        // which isn't possible with `init_module` functions, since a dry run doesn't write the
+        // published modules to the storage.
+        fn publish_and_init(
+            address: &AccountAddress,
+            init_modules: Vec<ModuleId>,
+            gas_limit: Option<u32>,
+            usage: StorageUsage,
+            publish: impl FnOnce(GasStrategy) -> Result<VmResult, Error<T>>,
+        ) -> Result<VmResult, Error<T>> {
+            ensure!(
+                gas_limit.is_some() || init_modules.is_empty(),
+                Error::<T>::PublishModuleFailed
+            );
+
+            let mut vm_result = publish(Self::gas_strategy(gas_limit, 0)?)?;
+
+            for module_id in init_modules {
+                if vm_result.status_code != StatusCode::EXECUTED {
+                    break;
+                }
+
+                let gas_used = vm_result.gas_used;
+                vm_result = Self::raw_init_module(
+                    &module_id,
+                    address,
+                    Self::gas_strategy(gas_limit, gas_used)?,
+                    usage.clone(),
+                )?;
+                vm_result.gas_used = vm_result.gas_used.saturating_add(gas_used);
+            }
+
+            Ok(vm_result)
+        }
+
+        // Returns a metered gas strategy for the gas left from the given limit, or a dry run
+        // without any limit.
+        fn gas_strategy(gas_limit: Option<u32>, gas_used: u64) -> Result<GasStrategy, Error<T>> {
+            let Some(gas_limit) = gas_limit else {
+                return Ok(GasStrategy::DryRun);
+            };
+
+            GasAmount::new(u64::from(gas_limit).saturating_sub(gas_used))
+                .map(GasStrategy::Metered)
+                .map_err(|_| Error::<T>::GasLimitExceeded)
+        }
+
+        // Converts a raw name into a Move identifier.
+        fn to_identifier(name: Vec<u8>) -> Result<Identifier, Error<T>> {
+            let name = String::from_utf8(name).map_err(|_| Error::<T>::InvalidEntryFunctionCall)?;
+            Identifier::new(name).map_err(|_| Error::<T>::InvalidEntryFunctionCall)
+        }
+
         pub(crate) fn extract_account_ids_from_args(
             script_args: &[&[u8]],
             signer_count: usize,
@@ -601,6 +1546,24 @@ pub mod pallet {
             Ok(())
         }
 
+        // Removes the cleanup chore of a multi-signer request, which is not pending anymore.
+        fn remove_multi_sign_request_chore(
+            multisig_creation_block_height: BlockNumberFor<T>,
+            hash: &CallHash,
+        ) {
+            let expires_at_block_height =
+                multisig_creation_block_height + T::MultisigReqExpireTime::get();
+
+            ChoreOnIdleStorage::<T>::mutate_exists(expires_at_block_height, |hashes| {
+                if let Some(hashes_ready_for_cleanup) = hashes {
+                    hashes_ready_for_cleanup.retain(|h| h != hash);
+                    if hashes_ready_for_cleanup.is_empty() {
+                        *hashes = None;
+                    }
+                }
+            });
+        }
+
         pub fn chore_multisig_storage(block: BlockNumberFor<T>) -> Weight {
             // Check storage, if we have an entry for this block.
             let Ok(hashes_ready_for_cleanup) = ChoreOnIdleStorage::<T>::try_get(block) else {
@@ -609,17 +1572,39 @@ pub mod pallet {
             // We don't need this entry in storage anymore, remove it.
             ChoreOnIdleStorage::<T>::remove(block);
 
-            // Remove all that entries from MultisigStorage.
+            // Remove all that entries from MultisigStorage and unlock the funds of their signers.
+            let mut signers = 0u64;
+            let mut released_locks = 0u64;
             for hash in hashes_ready_for_cleanup.iter() {
-                MultisigStorage::<T>::remove(hash);
+                // Already executed or cancelled requests don't exist anymore.
+                if let Some(sig_data) = Self::take_multisig_request(hash) {
+                    signers += sig_data.len() as u64;
+                    released_locks += ScriptSignatureHandler::<T>::from(sig_data).release_locks();
+                }
             }
 
             // Emit event about removed old multi-signer execution requests.
-            let writes = (hashes_ready_for_cleanup.len() as u64) + 1u64;
+            let requests = hashes_ready_for_cleanup.len() as u64;
             let call: Vec<CallHash> = hashes_ready_for_cleanup.into();
             Self::deposit_event(Event::<T>::MultiSignRequestRemoved { call });
 
-            T::DbWeight::get().reads_writes(1, writes)
+            // Every signer's entry gets removed from the index. Every released lock reads the
+            // locks, freezes and the account of the signer, and writes the locks and the account.
+            T::DbWeight::get().reads_writes(
+                1 + requests + 3 * released_locks,
+                1 + requests + signers + 2 * released_locks,
+            )
+        }
+
+        // Removes a multi-signer request from the `MultisigStorage` together with its entries in
+        // the `MultisigRequestsBySigner` index.
+        fn take_multisig_request(hash: &CallHash) -> Option<SigDataOf<T>> {
+            let sig_data = MultisigStorage::<T>::take(hash)?;
+            for signer in sig_data.keys() {
+                MultisigRequestsBySigner::<T>::remove(signer, hash);
+            }
+
+            Some(sig_data)
         }
 
         pub fn transaction_bc_call_hash(transaction_bc: &[u8]) -> CallHash {
@@ -648,7 +1633,18 @@ pub mod pallet {
             bytecode: Vec<u8>,
         ) -> Result<MoveApiEstimation, DispatchError> {
             let address = Self::to_move_address(account)?;
-            let vm_result = Self::raw_publish_module(&address, bytecode, GasStrategy::DryRun)?;
+            let init_modules = Self::new_init_modules(core::slice::from_ref(&bytecode))?;
+            let publish = |gas_limit| {
+                let usage = StorageUsage::default();
+                Self::publish_and_init(
+                    &address,
+                    init_modules.clone(),
+                    Self::publish_estimation_gas_limit(gas_limit, &init_modules),
+                    usage.clone(),
+                    |gas| Self::raw_publish_module(&address, bytecode.clone(), gas, usage),
+                )
+            };
+            let vm_result = Self::estimation_dry_run(&publish)?;
 
             Ok(MoveApiEstimation {
                 vm_status_code: vm_result.status_code.into(),
@@ -656,6 +1652,7 @@ pub mod pallet {
                 total_weight_including_gas_used: T::WeightInfo::publish_module_generic(
                     vm_result.gas_used as u32,
                 ),
+                recommended_gas_limit: Self::recommended_gas_limit(&vm_result, publish)?,
             })
         }
 
@@ -664,7 +1661,18 @@ pub mod pallet {
             bytecode: Vec<u8>,
         ) -> Result<MoveApiEstimation, DispatchError> {
             let address = Self::to_move_address(account)?;
-            let vm_result = Self::raw_publish_bundle(&address, bytecode, GasStrategy::DryRun)?;
+            let init_modules = Self::new_init_modules(&Self::bundle_modules(&bytecode))?;
+            let publish = |gas_limit| {
+                let usage = StorageUsage::default();
+                Self::publish_and_init(
+                    &address,
+                    init_modules.clone(),
+                    Self::publish_estimation_gas_limit(gas_limit, &init_modules),
+                    usage.clone(),
+                    |gas| Self::raw_publish_bundle(&address, bytecode.clone(), gas, usage),
+                )
+            };
+            let vm_result = Self::estimation_dry_run(&publish)?;
 
             Ok(MoveApiEstimation {
                 vm_status_code: vm_result.status_code.into(),
@@ -672,11 +1680,22 @@ pub mod pallet {
                 total_weight_including_gas_used: T::WeightInfo::publish_module_generic(
                     vm_result.gas_used as u32,
                 ),
+                recommended_gas_limit: Self::recommended_gas_limit(&vm_result, publish)?,
             })
         }
 
         pub fn rpc_estimate_gas_execute_script(
             transaction_bc: Vec<u8>,
+        ) -> Result<MoveApiEstimation, DispatchError> {
+            Self::rpc_estimate_gas_execute_script_with_cheques(
+                transaction_bc,
+                MoveApiChequeLimits::Unlimited,
+            )
+        }
+
+        pub fn rpc_estimate_gas_execute_script_with_cheques(
+            transaction_bc: Vec<u8>,
+            cheque_limits: MoveApiChequeLimits<T::AccountId>,
         ) -> Result<MoveApiEstimation, DispatchError> {
             // Main input for the VM are these script parameters.
             let ScriptTransaction {
@@ -688,25 +1707,211 @@ pub mod pallet {
             let args: Vec<&[u8]> = args.iter().map(AsRef::as_ref).collect();
 
             // Make sure the script parameters are valid.
-            let signer_count =
-                verify_script_integrity_and_check_signers(&bytecode).map_err(Error::<T>::from)?;
-
-            // In the case of a dry run, we have an "unlimited" balance (u128::MAX) because it is
-            // not relevant to the gas estimation (no changes will be applied).
-            let unlimited_balance = BalanceAdapter::<T>::for_dry_run(&args, signer_count)?;
-
-            let vm_result = Self::raw_execute_script(
-                &bytecode,
-                type_args,
-                args,
-                GasStrategy::DryRun,
-                unlimited_balance,
-            )?;
+            let signer_count = verify_script_integrity_and_check_signers(&bytecode)
+                .map_err(result::vm_error::<T>)?;
+
+            // By default, we have an "unlimited" balance (u128::MAX) in the case of a dry run,
+            // since no changes will be applied. Real balances or cheque-limits reveal scripts
+            // which would fail due to insufficient balances.
+            let limits: Vec<_> = match &cheque_limits {
+                MoveApiChequeLimits::Limits(limits) => limits
+                    .iter()
+                    .map(|(account, limit)| (account.clone(), BalanceOf::<T>::from(*limit)))
+                    .collect(),
+                _ => Vec::new(),
+            };
+            // Every execution requires its own balance adapter, since cheques get consumed.
+            let balance = || -> Result<BalanceAdapter<T>, DispatchError> {
+                Ok(match &cheque_limits {
+                    MoveApiChequeLimits::Unlimited => {
+                        BalanceAdapter::<T>::for_dry_run(&args, signer_count)?
+                    }
+                    MoveApiChequeLimits::FreeBalance => {
+                        BalanceAdapter::<T>::for_free_balances(&args, signer_count)?
+                    }
+                    MoveApiChequeLimits::Limits(_) => {
+                        BalanceAdapter::<T>::for_cheque_limits(&args, signer_count, &limits)?
+                    }
+                })
+            };
+            let execute = |gas_limit| -> Result<VmResult, DispatchError> {
+                let vm_result = Self::raw_execute_script(
+                    &bytecode,
+                    type_args.clone(),
+                    args.clone(),
+                    Self::gas_strategy(gas_limit, 0)?,
+                    balance()?,
+                    StorageUsage::default(),
+                )?;
+                Ok(vm_result)
+            };
+            let vm_result = Self::estimation_dry_run(&execute)?;
 
             Ok(MoveApiEstimation {
                 vm_status_code: vm_result.status_code.into(),
                 gas_used: vm_result.gas_used,
                 total_weight_including_gas_used: T::WeightInfo::execute(vm_result.gas_used as u32),
+                recommended_gas_limit: Self::recommended_gas_limit(&vm_result, execute)?,
+            })
+        }
+
+        // Executes a dry run of an estimated call, where `run` gets called without a gas limit.
+        // All changes get reverted, so that they don't affect the following metered runs.
+        // Returns the gas limit of a publication during an estimation. Since `init_module`
+        // functions can't be executed during a dry run, a publication with such functions is
+        // estimated by a metered run with the maximum gas limit instead. All changes of an
+        // estimation get reverted anyway.
+        fn publish_estimation_gas_limit(
+            gas_limit: Option<u32>,
+            init_modules: &[ModuleId],
+        ) -> Option<u32> {
+            if init_modules.is_empty() {
+                gas_limit
+            } else {
+                gas_limit.or(Some(u32::MAX))
+            }
+        }
+
+        fn estimation_dry_run<E: Into<DispatchError>>(
+            run: &impl Fn(Option<u32>) -> Result<VmResult, E>,
+        ) -> Result<VmResult, DispatchError> {
+            with_transaction(|| TransactionOutcome::Rollback(run(None).map_err(Into::into)))
+        }
+
+        // Finds the minimum gas limit for which a metered run succeeds, since the gas used during
+        // a dry run can slightly differ. Returns `None` if the dry run failed or if the metered
+        // run fails for another reason than running out of gas. All changes get reverted.
+        fn recommended_gas_limit<E: Into<DispatchError>>(
+            dry_run: &VmResult,
+            run: impl Fn(Option<u32>) -> Result<VmResult, E>,
+        ) -> Result<Option<u32>, DispatchError> {
+            if dry_run.status_code != StatusCode::EXECUTED {
+                return Ok(None);
+            }
+
+            // Returns `None` in case of any other error than running out of gas.
+            let succeeds = |gas_limit: u32| -> Result<Option<bool>, DispatchError> {
+                let vm_result = with_transaction(|| {
+                    TransactionOutcome::Rollback(run(Some(gas_limit)).map_err(Into::into))
+                })?;
+
+                Ok(match vm_result.status_code {
+                    StatusCode::EXECUTED => Some(true),
+                    StatusCode::OUT_OF_GAS => Some(false),
+                    _ => None,
+                })
+            };
+
+            // Usually, the gas used during the dry run is sufficient.
+            let mut low = u32::try_from(dry_run.gas_used).unwrap_or(u32::MAX);
+            match succeeds(low)? {
+                Some(true) => return Ok(Some(low)),
+                Some(false) => {}
+                None => return Ok(None),
+            }
+
+            // Find an upper bound by doubling the gas limit.
+            let mut high = low;
+            loop {
+                if high == u32::MAX {
+                    return Ok(None);
+                }
+                high = high.saturating_mul(2).max(1);
+                match succeeds(high)? {
+                    Some(true) => break,
+                    Some(false) => low = high,
+                    None => return Ok(None),
+                }
+            }
+
+            // The run fails with `low` and succeeds with `high`.
+            while high - low > 1 {
+                let mid = low + (high - low) / 2;
+                if succeeds(mid)? == Some(true) {
+                    high = mid;
+                } else {
+                    low = mid;
+                }
+            }
+
+            Ok(Some(high))
+        }
+
+        pub fn rpc_simulate_execute_script(
+            transaction_bc: Vec<u8>,
+        ) -> Result<MoveApiSimulation<T::AccountId>, DispatchError> {
+            // All changes are only recorded and reverted afterwards.
+            with_transaction(|| TransactionOutcome::Rollback(Self::simulate_script(transaction_bc)))
+        }
+
+        // Executes the script and collects all changes it applies to the storage and balances.
+        fn simulate_script(
+            transaction_bc: Vec<u8>,
+        ) -> Result<MoveApiSimulation<T::AccountId>, DispatchError> {
+            let ScriptTransaction {
+                bytecode,
+                args,
+                type_args,
+            } = ScriptTransaction::try_from(transaction_bc.as_ref())
+                .map_err(|_| Error::<T>::InvalidScriptTransaction)?;
+            let args: Vec<&[u8]> = args.iter().map(AsRef::as_ref).collect();
+
+            let signer_count = verify_script_integrity_and_check_signers(&bytecode)
+                .map_err(result::vm_error::<T>)?;
+            // Signers can't transfer more than they own, like in a real execution.
+            let balance = BalanceAdapter::<T>::for_free_balances(&args, signer_count)?;
+
+            // The caller reverts all changes afterwards, so the maximum gas limit of an extrinsic
+            // is used.
+            let gas_amount =
+                GasAmount::new(u32::MAX.into()).map_err(|_| Error::<T>::GasLimitExceeded)?;
+
+            let original_values = OriginalValues::default();
+            let storage = VmStorageAdapter::<T>::default().recording(original_values.clone());
+            let vm = Mvm::new(storage, balance.clone()).map_err(|_| Error::<T>::ExecuteFailed)?;
+            let vm_result =
+                vm.execute_script(&bytecode, type_args, args, GasStrategy::Metered(gas_amount));
+
+            let write_set = original_values
+                .borrow()
+                .iter()
+                .filter_map(|(key, original)| {
+                    let change = match (original, VMStorage::<T>::get(key)) {
+                        (None, Some(value)) => MoveApiStorageChange::Added(value),
+                        (Some(original), Some(value)) if *original != value => {
+                            MoveApiStorageChange::Modified(value)
+                        }
+                        (Some(_), None) => MoveApiStorageChange::Deleted,
+                        _ => return None,
+                    };
+                    Some(MoveApiWriteOp {
+                        key: key.clone(),
+                        change,
+                    })
+                })
+                .collect();
+
+            // Cheques of a failed execution will never be applied.
+            let balance_changes = if vm_result.status_code == StatusCode::EXECUTED {
+                balance.balance_changes()?
+            } else {
+                Vec::new()
+            };
+
+            Ok(MoveApiSimulation {
+                estimation: MoveApiEstimation {
+                    vm_status_code: vm_result.status_code.into(),
+                    gas_used: vm_result.gas_used,
+                    total_weight_including_gas_used: T::WeightInfo::execute(
+                        vm_result.gas_used as u32,
+                    ),
+                    // The gas estimation provides a verified gas limit.
+                    recommended_gas_limit: None,
+                },
+                write_set,
+                balance_changes,
+                // The MoveVM backend doesn't report emitted events yet.
+                events: Vec::new(),
             })
         }
 
@@ -730,6 +1935,90 @@ pub mod pallet {
         ) -> Result<Option<Vec<u8>>, Vec<u8>> {
             Self::get_resource(&account, tag.as_slice())
         }
+
+        pub fn rpc_get_resource_value(
+            account: T::AccountId,
+            tag: Vec<u8>,
+        ) -> Result<Option<MoveApiValue>, Vec<u8>> {
+            Self::get_resource_value(&account, tag.as_slice())
+        }
+
+        pub fn rpc_error_name(error: DispatchError) -> Option<String> {
+            let error = Self::pallet_error(error)?;
+            Error::<T>::decode(&mut &error.error[..])
+                .ok()
+                .map(|error| error.as_str().into())
+        }
+
+        pub fn rpc_error_vm_status(error: DispatchError) -> Option<u64> {
+            result::vm_status_of(&Self::pallet_error(error)?).map(Into::into)
+        }
+
+        // Returns the module error, if it has been returned by this pallet.
+        fn pallet_error(error: DispatchError) -> Option<ModuleError> {
+            let DispatchError::Module(error) = error else {
+                return None;
+            };
+            // Errors of other pallets can't be decoded here.
+            if usize::from(error.index) != <Self as PalletInfoAccess>::index() {
+                return None;
+            }
+
+            Some(error)
+        }
+
+        pub fn rpc_list_resources(
+            account: T::AccountId,
+            start: Option<Vec<u8>>,
+            limit: Option<u32>,
+        ) -> Result<Vec<Vec<u8>>, Vec<u8>> {
+            Self::list_resources(&account, start, limit)
+        }
+
+        pub fn rpc_list_modules(
+            account: T::AccountId,
+            with_hashes: bool,
+        ) -> Result<Vec<MoveApiModuleInfo>, Vec<u8>> {
+            Self::list_modules(&account, with_hashes)
+        }
+
+        pub fn rpc_get_multisig_request(
+            call_hash: CallHash,
+        ) -> Option<MoveApiMultisigRequest<T::AccountId>> {
+            MultisigStorage::<T>::get(call_hash)
+                .map(|sig_data| Self::multisig_request_info(call_hash, sig_data))
+        }
+
+        pub fn rpc_pending_multisig_requests_for(
+            account: T::AccountId,
+        ) -> Vec<MoveApiMultisigRequest<T::AccountId>> {
+            MultisigRequestsBySigner::<T>::iter_key_prefix(&account)
+                .filter_map(Self::rpc_get_multisig_request)
+                .collect()
+        }
+
+        fn multisig_request_info(
+            call_hash: CallHash,
+            sig_data: SigDataOf<T>,
+        ) -> MoveApiMultisigRequest<T::AccountId> {
+            let expires_at = sig_data.stored_block_height().map(|block_height| {
+                (*block_height + T::MultisigReqExpireTime::get()).saturated_into()
+            });
+            let signers = sig_data
+                .iter()
+                .map(|(account, ms_data)| MoveApiMultisigSigner {
+                    account: account.clone(),
+                    approved: ms_data.signature == Signature::Approved,
+                    cheque_limit: ms_data.cheque_limit.into(),
+                })
+                .collect();
+
+            MoveApiMultisigRequest {
+                call_hash,
+                signers,
+                expires_at,
+            }
+        }
     }
 
     #[pallet::error]
@@ -759,6 +2048,36 @@ pub mod pallet {
         MaxSignersExceeded,
         /// No space left in the ChoreOnIdleStorage during this block.
         ChoreOnIdleVecOverflow,
+        /// Entry function call contains invalid names or type arguments.
+        InvalidEntryFunctionCall,
+        /// The called function is not declared as an entry function.
+        FunctionNotEntry,
+        /// The module cannot be found under the user's address.
+        ModuleNotFound,
+        /// The module name is not a valid Move identifier.
+        InvalidModuleName,
+        /// The module has been made immutable by its publisher and cannot be updated.
+        ModuleImmutable,
+        /// Upgrade policies can only be tightened.
+        UpgradePolicyNotAllowed,
+        /// Not enough free balance to reserve the storage deposit.
+        InsufficientStorageDeposit,
+        /// Not enough free balance to pay the gas fee for the given gas limit.
+        InsufficientBalanceForGasFee,
+        /// Only the `ReservedAddressOrigin` can publish modules at a reserved address.
+        ReservedAddressNotAllowed,
+        /// The address is already reserved.
+        AddressAlreadyReserved,
+        /// The address is not reserved.
+        AddressNotReserved,
+        /// The address already holds modules or resources, so it can't be reserved.
+        AddressNotEmpty,
+        /// The bundle contains more modules than declared by the call.
+        ModuleCountExceeded,
+        /// There is no pending multi-signer request for the given call hash.
+        MultisigRequestNotFound,
+        /// The user hasn't signed the multi-signer request.
+        SignatureNotApproved,
 
         // Errors that can be received from MoveVM
         /// Unknown validation status
@@ -0,0 +1,149 @@
+//! Storage migrations of the pallet, which have to be added to the runtime's multi-block
+//! migrations (`pallet_migrations`).
+//!
+//! Every migration processes as many entries per block as the weight limit allows, so they can
+//! be applied to storages of any size.
+
+use codec::Encode;
+use frame_support::{
+    migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+    pallet_prelude::*,
+    storage::StoragePrefixedMap,
+    traits::GetStorageVersion,
+    weights::WeightMeter,
+};
+use sp_core::hashing::blake2_128;
+use sp_std::marker::PhantomData;
+
+use crate::{
+    signer::CallHash, storage::split_key, Config, MultisigRequestsBySigner, MultisigStorage,
+    Pallet, VMStorage, VMStorageKeys,
+};
+
+/// Identifier of the pallet's migrations.
+const PALLET_MIGRATIONS_ID: &[u8; 11] = b"pallet-move";
+
+/// Migration to storage version 1, which indexes the keys of the MoveVM storage per owner.
+pub mod v1 {
+    use super::*;
+
+    /// Fills `VMStorageKeys` with all keys already stored in `VMStorage`.
+    ///
+    /// The cursor is the hash of the next key to be indexed, which is the first part of its
+    /// `Blake2_128Concat` storage key, so that the cursor has a fixed size.
+    pub struct IndexVMStorageKeys<T>(PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for IndexVMStorageKeys<T> {
+        type Cursor = [u8; 16];
+        type Identifier = MigrationId<11>;
+
+        fn id() -> Self::Identifier {
+            MigrationId {
+                pallet_id: *PALLET_MIGRATIONS_ID,
+                version_from: 0,
+                version_to: 1,
+            }
+        }
+
+        fn step(
+            cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if cursor.is_none() && Pallet::<T>::on_chain_storage_version() != 0 {
+                return Ok(None);
+            }
+
+            // Reading the next key and writing its index entry.
+            let required = T::DbWeight::get().reads_writes(1, 1);
+            if meter.remaining().any_lt(required) {
+                return Err(SteppedMigrationError::InsufficientWeight { required });
+            }
+
+            let keys = match cursor {
+                Some(hash) => {
+                    let mut start = VMStorage::<T>::final_prefix().to_vec();
+                    start.extend(hash);
+                    VMStorage::<T>::iter_keys_from(start)
+                }
+                None => VMStorage::<T>::iter_keys(),
+            };
+            for key in keys {
+                if meter.try_consume(required).is_err() {
+                    return Ok(Some(blake2_128(&key.encode())));
+                }
+
+                let Some((owner, tag, path)) = split_key(&key) else {
+                    continue;
+                };
+                if let Ok(owner) = Pallet::<T>::to_native_account(&owner) {
+                    VMStorageKeys::<T>::insert((owner, tag), path, ());
+                }
+            }
+
+            StorageVersion::new(1).put::<Pallet<T>>();
+            Ok(None)
+        }
+    }
+}
+
+/// Migration to storage version 2, which indexes the pending multi-signer requests per signer.
+pub mod v2 {
+    use super::*;
+
+    /// Fills `MultisigRequestsBySigner` with the signers of all requests in `MultisigStorage`.
+    ///
+    /// The cursor is the call hash of the last indexed request.
+    pub struct IndexMultisigRequests<T>(PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for IndexMultisigRequests<T> {
+        type Cursor = CallHash;
+        type Identifier = MigrationId<11>;
+
+        fn id() -> Self::Identifier {
+            MigrationId {
+                pallet_id: *PALLET_MIGRATIONS_ID,
+                version_from: 1,
+                version_to: 2,
+            }
+        }
+
+        fn step(
+            mut cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if cursor.is_none() && Pallet::<T>::on_chain_storage_version() != 1 {
+                return Ok(None);
+            }
+
+            // Reading the next request and writing the index entries of all its signers.
+            let required =
+                T::DbWeight::get().reads_writes(1, u64::from(T::MaxScriptSigners::get()));
+            if meter.remaining().any_lt(required) {
+                return Err(SteppedMigrationError::InsufficientWeight { required });
+            }
+
+            let mut requests = match cursor {
+                Some(call_hash) => {
+                    MultisigStorage::<T>::iter_from(MultisigStorage::<T>::hashed_key_for(call_hash))
+                }
+                None => MultisigStorage::<T>::iter(),
+            };
+            loop {
+                if meter.try_consume(required).is_err() {
+                    return Ok(cursor);
+                }
+
+                let Some((call_hash, sig_data)) = requests.next() else {
+                    break;
+                };
+                for signer in sig_data.keys() {
+                    MultisigRequestsBySigner::<T>::insert(signer, call_hash, ());
+                }
+                cursor = Some(call_hash);
+            }
+
+            StorageVersion::new(2).put::<Pallet<T>>();
+            Ok(None)
+        }
+    }
+}
@@ -7,7 +7,7 @@ use frame_support::{
     parameter_types,
     traits::{ConstU128, ConstU16, ConstU32, ConstU64},
 };
-use frame_system::pallet_prelude::BlockNumberFor;
+use frame_system::{pallet_prelude::BlockNumberFor, EnsureRoot};
 use sp_core::H256;
 use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
 use sp_runtime::BuildStorage;
@@ -98,6 +98,9 @@ impl pallet_balances::Config for Test {
 parameter_types! {
     pub const MultisigReqExpireTime: BlockNumberFor<Test> = 5;
     pub const MaxScriptSigners: u32 = 8;
+    pub static DepositPerByte: Balance = 0;
+    pub static DepositPerItem: Balance = 0;
+    pub static GasPrice: Balance = 0;
 }
 
 impl pallet_move::Config for Test {
@@ -105,6 +108,12 @@ impl pallet_move::Config for Test {
     type CurrencyBalance = Balance;
     type MultisigReqExpireTime = MultisigReqExpireTime;
     type MaxScriptSigners = MaxScriptSigners;
+    type DepositPerByte = DepositPerByte;
+    type DepositPerItem = DepositPerItem;
+    type GasPrice = GasPrice;
+    type OnGasFee = ();
+    type StdlibUpdateOrigin = EnsureRoot<AccountId32>;
+    type ReservedAddressOrigin = EnsureRoot<AccountId32>;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_move::weights::SubstrateWeight<Test>;
 }
@@ -4,15 +4,22 @@ use frame_support::{
 };
 use move_core_types::vm_status::StatusCode;
 use move_vm_backend::types::VmResult;
+use sp_runtime::{DispatchError, ModuleError};
 
 use super::{Config, Error};
 
 /// Converts VM result to dispatch result.
 ///
 /// VM returns the VM result, so we use the current function to convert it to DispatchResultWithPostInfo.
-pub fn from_vm_result<T: Config>(vm_result: VmResult) -> DispatchResultWithPostInfo {
-    //todo: add real gas weight mapping
-    let weight = Weight::from_all(vm_result.gas_used);
+/// The actual weight is calculated by `weight_of` for the used gas, the same benchmarked function
+/// used for the pre-dispatch weight of the `gas_limit`, so the unused gas gets refunded.
+pub fn from_vm_result<T: Config>(
+    vm_result: VmResult,
+    weight_of: impl FnOnce(u32) -> Weight,
+) -> DispatchResultWithPostInfo {
+    // The used gas can never exceed the gas limit of the extrinsic.
+    let gas_used = u32::try_from(vm_result.gas_used).unwrap_or(u32::MAX);
+    let weight = weight_of(gas_used);
 
     let gas = PostDispatchInfo {
         actual_weight: Some(weight),
@@ -24,12 +31,49 @@ pub fn from_vm_result<T: Config>(vm_result: VmResult) -> DispatchResultWithPostI
         status_code => Err({
             DispatchErrorWithPostInfo {
                 post_info: gas,
-                error: Error::<T>::from(status_code).into(),
+                error: vm_error::<T>(status_code),
             }
         }),
     }
 }
 
+// The status code of a MoveVM error is stored within the three spare bytes of the module error,
+// incremented by one, so that zero bytes mean no status code. Larger status codes don't fit in,
+// they are stored as all ones like `UNKNOWN_STATUS`.
+const UNKNOWN_STATUS_BYTES: [u8; 3] = [u8::MAX; 3];
+
+/// Converts a MoveVM status code into a dispatch error.
+///
+/// Besides the pallet error of the same name, the dispatch error carries the status code itself,
+/// which can be read by `vm_status_of`. Errors raised by the pallet itself don't carry any.
+pub fn vm_error<T: Config>(status_code: StatusCode) -> DispatchError {
+    let mut error: DispatchError = Error::<T>::from(status_code).into();
+
+    if let DispatchError::Module(ModuleError { error: bytes, .. }) = &mut error {
+        let status_bytes = match u64::from(status_code).checked_add(1) {
+            Some(value) if value < 0xff_ffff => {
+                let [b0, b1, b2, ..] = value.to_le_bytes();
+                [b0, b1, b2]
+            }
+            _ => UNKNOWN_STATUS_BYTES,
+        };
+        bytes[1..].copy_from_slice(&status_bytes);
+    }
+
+    error
+}
+
+/// Returns the MoveVM status code a module error of this pallet has been converted from by
+/// `vm_error`.
+pub fn vm_status_of(error: &ModuleError) -> Option<StatusCode> {
+    let [_, b0, b1, b2] = error.error;
+    match [b0, b1, b2] {
+        [0, 0, 0] => None,
+        UNKNOWN_STATUS_BYTES => Some(StatusCode::UNKNOWN_STATUS),
+        _ => StatusCode::try_from(u64::from_le_bytes([b0, b1, b2, 0, 0, 0, 0, 0]) - 1).ok(),
+    }
+}
+
 /// Generates a simple PostDispatchInfo for only signing in extrinsic `execute`.
 pub fn execute_only_signing() -> DispatchResultWithPostInfo {
     Ok(PostDispatchInfo {
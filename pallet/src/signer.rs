@@ -29,6 +29,11 @@ pub type SigDataOf<T> = SigData<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>,
 /// This definition stores the hash value of a script transaction.
 pub type CallHash = [u8; 32];
 
+/// Prefix of the hashed call data of an entry function call, which separates its call hashes
+/// from the ones of script transactions. A script transaction can't start with this tag, since it
+/// would have to be followed by the Move bytecode magic.
+pub const ENTRY_FUNCTION_CALL_TAG: &[u8; 14] = b"entry_function";
+
 /// A simple signature.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum Signature {
@@ -213,6 +218,21 @@ impl<T: Config + SysConfig> ScriptSignatureHandler<T> {
         Ok(())
     }
 
+    /// Revoke a previously provided signature and unlock the signer's funds again.
+    pub(crate) fn revoke_signature(&mut self, account: &T::AccountId) -> Result<(), Error<T>> {
+        let Some(ms_data) = self.sig_info.get_mut(account) else {
+            return Err(Error::<T>::UnexpectedUserSignature);
+        };
+
+        if !matches!(ms_data.signature, Signature::Approved) {
+            return Err(Error::<T>::SignatureNotApproved);
+        }
+
+        T::Currency::remove_lock(ms_data.lock_id, account);
+        *ms_data = SignerData::default();
+        Ok(())
+    }
+
     /// Check whether the script has been approved by all required signers.
     pub(crate) fn all_signers_approved(&self) -> bool {
         self.sig_info
@@ -234,6 +254,20 @@ impl<T: Config + SysConfig> ScriptSignatureHandler<T> {
         Ok(balances)
     }
 
+    /// Unlocks the funds of all signers who have already signed the script and returns the
+    /// number of released locks.
+    pub(crate) fn release_locks(&self) -> u64 {
+        let mut released = 0;
+        for (account, ms_data) in self.sig_info.iter() {
+            if matches!(ms_data.signature, Signature::Approved) {
+                T::Currency::remove_lock(ms_data.lock_id, account);
+                released += 1;
+            }
+        }
+
+        released
+    }
+
     /// Consumes [`ScriptSignatureHandler`] and returns inner `SigData`.
     pub(crate) fn into_inner(self) -> SigDataOf<T> {
         self.sig_info
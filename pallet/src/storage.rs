@@ -2,15 +2,20 @@ use core::marker::PhantomData;
 
 use codec::{FullCodec, FullEncode};
 use frame_support::storage::StorageMap;
+use move_core_types::{
+    account_address::AccountAddress,
+    language_storage::{ModuleId, StructTag},
+};
 use move_vm_backend::storage::Storage;
-use sp_std::prelude::*;
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap, prelude::*, rc::Rc};
 
 /// Move Virtual Machine storage trait used to represent the native storage.
 pub trait MoveVmStorage<T, K: FullEncode, V: FullCodec> {
     type VmStorage;
+    type VmStorageIndex;
 
     /// Create a new instance of the VM storage.
-    fn move_vm_storage() -> StorageAdapter<Self::VmStorage, K, V>
+    fn move_vm_storage() -> StorageAdapter<Self::VmStorage, K, V, Self::VmStorageIndex>
     where
         Self::VmStorage: StorageMap<K, V, Query = Option<V>>,
     {
@@ -18,19 +23,138 @@ pub trait MoveVmStorage<T, K: FullEncode, V: FullCodec> {
     }
 }
 
-/// Vm storage adapter for native storage.
-pub struct StorageAdapter<T, K = Vec<u8>, V = Vec<u8>>(PhantomData<(T, K, V)>);
+/// Index of the MoveVM storage keys per owner's address, maintained by the [`StorageAdapter`], so
+/// that the entries of a single account can be found without iterating the whole storage.
+///
+/// Keys are passed split into the owner's address, the tag of the access path and the access path.
+pub trait StorageKeyIndex {
+    /// Adds a newly created key.
+    fn insert(owner: &AccountAddress, tag: u8, path: &[u8]);
+    /// Removes a deleted key.
+    fn remove(owner: &AccountAddress, tag: u8, path: &[u8]);
+}
+
+/// No index at all.
+impl StorageKeyIndex for () {
+    fn insert(_owner: &AccountAddress, _tag: u8, _path: &[u8]) {}
+    fn remove(_owner: &AccountAddress, _tag: u8, _path: &[u8]) {}
+}
+
+/// Net change of the storage usage of a single account.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StorageUsageDelta {
+    /// Change of the number of stored bytes, including the keys.
+    pub bytes: i64,
+    /// Change of the number of stored items.
+    pub items: i64,
+}
+
+/// Shared record of the storage usage changes per account, written by the [`StorageAdapter`].
+pub type StorageUsage = Rc<RefCell<BTreeMap<AccountAddress, StorageUsageDelta>>>;
+
+/// Shared record of the original values of all keys written by the [`StorageAdapter`], where
+/// `None` means that the entry didn't exist before.
+pub type OriginalValues = Rc<RefCell<BTreeMap<Vec<u8>, Option<Vec<u8>>>>>;
+
+/// Tag of the access paths of modules within the MoveVM storage keys.
+pub const CODE_TAG: u8 = 0;
+/// Tag of the access paths of resources within the MoveVM storage keys.
+pub const RESOURCE_TAG: u8 = 1;
+
+/// Splits a MoveVM storage key into the owner's address, the tag of the access path and the
+/// BCS-encoded access path, which is a [`ModuleId`] or a [`StructTag`].
+pub fn split_key(key: &[u8]) -> Option<(AccountAddress, u8, &[u8])> {
+    let address = key.get(..AccountAddress::LENGTH)?;
+    let owner = AccountAddress::from_bytes(address).ok()?;
+    let (tag, path) = key[AccountAddress::LENGTH..].split_first()?;
+
+    Some((owner, *tag, path))
+}
+
+/// Entry of the MoveVM storage, as identified by its key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StorageEntry {
+    /// Bytecode of a published module.
+    Module(ModuleId),
+    /// Resource of the given type.
+    Resource(StructTag),
+}
+
+impl StorageEntry {
+    /// Decodes the owner's address and the stored entry from a MoveVM storage key.
+    ///
+    /// Every key consists of the owner's address followed by the tag of the access path and the
+    /// BCS-encoded [`ModuleId`] or [`StructTag`].
+    pub fn from_key(key: &[u8]) -> Option<(AccountAddress, Self)> {
+        let (owner, tag, path) = split_key(key)?;
+
+        Some((owner, Self::from_path(tag, path)?))
+    }
+
+    /// Decodes the stored entry from the tag and the access path of a MoveVM storage key.
+    pub fn from_path(tag: u8, path: &[u8]) -> Option<Self> {
+        match tag {
+            CODE_TAG => bcs::from_bytes(path).ok().map(Self::Module),
+            RESOURCE_TAG => bcs::from_bytes(path).ok().map(Self::Resource),
+            _ => None,
+        }
+    }
+}
+
+/// Vm storage adapter for native storage, which maintains the key index `I` next to the storage.
+pub struct StorageAdapter<T, K = Vec<u8>, V = Vec<u8>, I = ()> {
+    _pd: PhantomData<(T, K, V, I)>,
+    /// Record of the storage usage changes made through this adapter.
+    usage: StorageUsage,
+    /// Optional record of the original values, e.g. for reporting a write set.
+    original_values: Option<OriginalValues>,
+}
+
+impl<T, K, V, I> StorageAdapter<T, K, V, I> {
+    /// Create a new storage adapter which records all storage usage changes into `usage`.
+    pub fn with_usage(usage: StorageUsage) -> Self {
+        Self {
+            _pd: PhantomData,
+            usage,
+            original_values: None,
+        }
+    }
+
+    /// Additionally records the original values of all keys written through this adapter.
+    pub fn recording(mut self, original_values: OriginalValues) -> Self {
+        self.original_values = Some(original_values);
+        self
+    }
+
+    // Records the storage usage change of a single key, where `old_len` and `new_len` are the
+    // value lengths before and after the change (`None` if the entry doesn't exist).
+    fn track(&self, key: &[u8], old_len: Option<usize>, new_len: Option<usize>) {
+        let Some((owner, ..)) = split_key(key) else {
+            return;
+        };
+
+        let size = |len: Option<usize>| len.map_or(0, |len| (key.len() + len) as i64);
+        let count = |len: Option<usize>| i64::from(len.is_some());
+
+        let mut usage = self.usage.borrow_mut();
+        let delta = usage.entry(owner).or_default();
+        delta.bytes += size(new_len) - size(old_len);
+        delta.items += count(new_len) - count(old_len);
+    }
+}
 
 /// Default trait VM storage adapter implementation
-impl<T, K, V> Default for StorageAdapter<T, K, V> {
+impl<T, K, V, I> Default for StorageAdapter<T, K, V, I> {
     fn default() -> Self {
-        Self(Default::default())
+        Self::with_usage(Default::default())
     }
 }
 
 /// Storage trait implementation for the StorageAdapter struct.
-impl<T: StorageMap<Vec<u8>, Vec<u8>, Query = Option<Vec<u8>>>> Storage
-    for StorageAdapter<T, Vec<u8>, Vec<u8>>
+impl<T, I> Storage for StorageAdapter<T, Vec<u8>, Vec<u8>, I>
+where
+    T: StorageMap<Vec<u8>, Vec<u8>, Query = Option<Vec<u8>>>,
+    I: StorageKeyIndex,
 {
     /// Get a value specified by key.
     fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
@@ -39,11 +163,38 @@ impl<T: StorageMap<Vec<u8>, Vec<u8>, Query = Option<Vec<u8>>>> Storage
 
     /// Set (insert) a value specified by key.
     fn set(&self, key: &[u8], value: &[u8]) {
+        self.record_original_value(key);
+        let old_len = T::decode_len(key);
+        self.track(key, old_len, Some(value.len()));
+        if let (None, Some((owner, tag, path))) = (old_len, split_key(key)) {
+            I::insert(&owner, tag, path);
+        }
         T::insert(key, value)
     }
 
     /// Remove a value specified by key and the key itself.
     fn remove(&self, key: &[u8]) {
+        self.record_original_value(key);
+        let old_len = T::decode_len(key);
+        self.track(key, old_len, None);
+        if let (Some(_), Some((owner, tag, path))) = (old_len, split_key(key)) {
+            I::remove(&owner, tag, path);
+        }
         T::remove(key)
     }
 }
+
+impl<T, I> StorageAdapter<T, Vec<u8>, Vec<u8>, I>
+where
+    T: StorageMap<Vec<u8>, Vec<u8>, Query = Option<Vec<u8>>>,
+{
+    // Records the value of the given key before its first modification.
+    fn record_original_value(&self, key: &[u8]) {
+        if let Some(original_values) = &self.original_values {
+            original_values
+                .borrow_mut()
+                .entry(key.to_vec())
+                .or_insert_with(|| T::get(key));
+        }
+    }
+}
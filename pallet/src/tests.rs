@@ -2,11 +2,18 @@
 
 mod address;
 mod balance;
+mod entry_function;
 mod example;
 mod execute;
 #[cfg(feature = "gas-cost-measurement")]
 mod gas_costs;
+mod gas_fee;
+mod migrations;
 mod modules;
 mod publish;
+mod reserved_address;
+mod rpc;
 mod signer;
+mod simulate;
+mod storage_deposit;
 mod update_stdlib;
@@ -1,10 +1,12 @@
 use crate::{
+    api::MoveApiChequeLimits,
     balance::{BalanceAdapter, BalanceOf},
     mock::*,
     mock_utils as utils, no_type_args, script_transaction,
 };
 
 use frame_support::assert_ok;
+use move_core_types::vm_status::StatusCode;
 use move_vm_backend::balance::BalanceHandler;
 
 #[test]
@@ -334,3 +336,72 @@ fn verify_self_transfer_trying_to_cheat() {
             assert_eq!(now_blnc_alice, BALANCE);
         })
 }
+
+#[test]
+fn estimate_transfer_with_real_balances() {
+    const BALANCE: u128 = 10_000;
+
+    let (alice_addr_32, alice_addr_mv) = utils::account_n_address::<Test>(utils::ALICE_ADDR);
+    let (_, bob_addr_mv) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default()
+        .with_balances(vec![(alice_addr_32.clone(), BALANCE)])
+        .build()
+        .execute_with(|| {
+            let script = utils::read_script_from_project("balance", "single_transfer");
+            let transaction_bc = |amount: u128| {
+                script_transaction!(
+                    script.clone(),
+                    no_type_args!(),
+                    &alice_addr_mv,
+                    &bob_addr_mv,
+                    &amount
+                )
+            };
+            let status_code = |amount, cheque_limits| {
+                MoveModule::rpc_estimate_gas_execute_script_with_cheques(
+                    transaction_bc(amount),
+                    cheque_limits,
+                )
+                .unwrap()
+                .vm_status_code
+            };
+            let executed = u64::from(StatusCode::EXECUTED);
+
+            // With unlimited cheques, insufficient balances don't show up.
+            assert_eq!(
+                status_code(2 * BALANCE, MoveApiChequeLimits::Unlimited),
+                executed
+            );
+
+            assert_eq!(
+                status_code(BALANCE / 2, MoveApiChequeLimits::FreeBalance),
+                executed
+            );
+            assert_ne!(
+                status_code(2 * BALANCE, MoveApiChequeLimits::FreeBalance),
+                executed
+            );
+
+            let limits = vec![(alice_addr_32.clone(), BALANCE / 2)];
+            assert_eq!(
+                status_code(BALANCE / 2, MoveApiChequeLimits::Limits(limits.clone())),
+                executed
+            );
+            assert_ne!(
+                status_code(BALANCE, MoveApiChequeLimits::Limits(limits)),
+                executed
+            );
+            assert_ne!(
+                status_code(BALANCE / 2, MoveApiChequeLimits::Limits(vec![])),
+                executed
+            );
+
+            // Cheque-limits above the free balance are rejected.
+            assert!(MoveModule::rpc_estimate_gas_execute_script_with_cheques(
+                transaction_bc(BALANCE),
+                MoveApiChequeLimits::Limits(vec![(alice_addr_32.clone(), 2 * BALANCE)]),
+            )
+            .is_err());
+        })
+}
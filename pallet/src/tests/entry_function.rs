@@ -0,0 +1,143 @@
+//! Integration tests related to extrinsic call `execute_entry_function`.
+
+use crate::{mock::*, mock_utils as utils, Error, Event};
+
+use frame_support::{assert_err, assert_ok, pallet_prelude::DispatchResultWithPostInfo};
+use move_core_types::{identifier::Identifier, language_storage::StructTag};
+
+fn execute_entry_function(
+    who: &AccountId32,
+    module_address: &AccountId32,
+    module_name: &str,
+    function_name: &str,
+    args: Vec<Vec<u8>>,
+) -> DispatchResultWithPostInfo {
+    MoveModule::execute_entry_function(
+        RuntimeOrigin::signed(who.clone()),
+        module_address.clone(),
+        module_name.as_bytes().to_vec(),
+        function_name.as_bytes().to_vec(),
+        vec![],
+        args,
+        MAX_GAS_AMOUNT,
+        EMPTY_CHEQUE,
+    )
+}
+
+fn publish_basic_coin(who: &AccountId32) {
+    let bundle = utils::read_bundle_from_project("basic_coin", "basic_coin");
+    assert_ok!(MoveModule::publish_module_bundle(
+        RuntimeOrigin::signed(who.clone()),
+        bundle,
+        MAX_GAS_AMOUNT,
+    ));
+}
+
+fn coin_balance_tag(module_owner: &AccountAddress) -> Vec<u8> {
+    let tag = StructTag {
+        address: *module_owner,
+        module: Identifier::new("BasicCoin").unwrap(),
+        name: Identifier::new("Balance").unwrap(),
+        type_params: vec![],
+    };
+    bcs::to_bytes(&tag).unwrap()
+}
+
+/// An entry function can be called without a script.
+#[test]
+fn execute_entry_function_works() {
+    let (bob_addr_32, bob_addr_mv) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        // Roll to first block in case of block based event checkings and processes.
+        roll_to(1);
+
+        publish_basic_coin(&bob_addr_32);
+        let tag = coin_balance_tag(&bob_addr_mv);
+        assert_eq!(MoveModule::get_resource(&bob_addr_32, &tag), Ok(None));
+
+        // publish_balance(account: &signer)
+        let args = vec![bcs::to_bytes(&bob_addr_mv).unwrap()];
+        assert_ok!(execute_entry_function(
+            &bob_addr_32,
+            &bob_addr_32,
+            "BasicCoin",
+            "publish_balance",
+            args,
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::MoveModule(Event::<Test>::ExecuteCalled {
+                who: vec![bob_addr_32.clone()]
+            })
+        );
+
+        assert!(MoveModule::get_resource(&bob_addr_32, &tag)
+            .unwrap()
+            .is_some());
+    })
+}
+
+/// Only the signers of an entry function call can execute it.
+#[test]
+fn execute_entry_function_by_the_wrong_user_fails() {
+    let (bob_addr_32, bob_addr_mv) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+    let (alice_addr_32, _) = utils::account_n_address::<Test>(utils::ALICE_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        publish_basic_coin(&bob_addr_32);
+
+        let args = vec![bcs::to_bytes(&bob_addr_mv).unwrap()];
+        assert_err!(
+            execute_entry_function(
+                &alice_addr_32,
+                &bob_addr_32,
+                "BasicCoin",
+                "publish_balance",
+                args,
+            ),
+            Error::<Test>::UnexpectedUserSignature
+        );
+    })
+}
+
+/// Functions which are not declared as `entry` cannot be called.
+#[test]
+fn execute_non_entry_function_fails() {
+    let (bob_addr_32, bob_addr_mv) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        publish_basic_coin(&bob_addr_32);
+
+        // mint(module_owner: &signer, mint_addr: address, amount: u64)
+        let args = vec![
+            bcs::to_bytes(&bob_addr_mv).unwrap(),
+            bcs::to_bytes(&bob_addr_mv).unwrap(),
+            bcs::to_bytes(&100u64).unwrap(),
+        ];
+        assert_err!(
+            execute_entry_function(&bob_addr_32, &bob_addr_32, "BasicCoin", "mint", args),
+            Error::<Test>::FunctionNotEntry
+        );
+    })
+}
+
+/// Calling functions of unpublished modules fails.
+#[test]
+fn execute_entry_function_of_unknown_module_fails() {
+    let (bob_addr_32, bob_addr_mv) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        let args = vec![bcs::to_bytes(&bob_addr_mv).unwrap()];
+        assert_err!(
+            execute_entry_function(
+                &bob_addr_32,
+                &bob_addr_32,
+                "BasicCoin",
+                "publish_balance",
+                args,
+            ),
+            Error::<Test>::LinkerError
+        );
+    })
+}
@@ -1,6 +1,6 @@
-use crate::{mock::*, mock_utils as utils, no_type_args, script_transaction, Error};
+use crate::{mock::*, mock_utils as utils, no_type_args, script_transaction};
 
-use frame_support::{assert_err, assert_ok};
+use frame_support::assert_ok;
 use move_core_types::{identifier::Identifier, language_storage::StructTag};
 
 fn get_vm_resource(
@@ -94,7 +94,7 @@ fn execute_script_generic_fails() {
             0,
         );
 
-        assert_err!(res, Error::<Test>::InvalidMainFunctionSignature);
+        assert!(verify_module_error_with_msg(res, "InvalidMainFunctionSignature").unwrap());
     });
 }
 
@@ -0,0 +1,147 @@
+//! Integration tests related to gas fees charged in the native currency.
+
+use crate::{mock::*, mock_utils as utils, Call, Error, GasStrategy};
+
+use frame_support::{assert_err, assert_ok};
+use sp_runtime::traits::Dispatchable;
+
+const GAS_PRICE: Balance = 2;
+const INITIAL_BALANCE: Balance = 1_000_000_000_000;
+
+/// Only the fee for the used gas is charged, the rest gets refunded.
+#[test]
+fn publish_module_charges_used_gas() {
+    let (bob_addr_32, bob_addr_mv) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default()
+        .with_balances(vec![(bob_addr_32.clone(), INITIAL_BALANCE)])
+        .build()
+        .execute_with(|| {
+            GasPrice::set(GAS_PRICE);
+
+            let module = utils::read_module_from_project("move-basics", "EmptyBob");
+            let gas_used = MoveModule::raw_publish_module(
+                &bob_addr_mv,
+                module.clone(),
+                GasStrategy::DryRun,
+                Default::default(),
+            )
+            .expect("failed to publish a module")
+            .gas_used as Balance;
+
+            assert_ok!(MoveModule::publish_module(
+                RuntimeOrigin::signed(bob_addr_32.clone()),
+                module,
+                MAX_GAS_AMOUNT,
+            ));
+
+            assert_eq!(
+                Balances::free_balance(&bob_addr_32),
+                INITIAL_BALANCE - gas_used * GAS_PRICE
+            );
+        })
+}
+
+/// The fee for the whole gas limit has to be available before the execution.
+#[test]
+fn publish_module_without_gas_fee_fails() {
+    let bob_addr_32 = utils::account::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default()
+        .with_balances(vec![(bob_addr_32.clone(), INITIAL_BALANCE)])
+        .build()
+        .execute_with(|| {
+            GasPrice::set(INITIAL_BALANCE);
+
+            let module = utils::read_module_from_project("move-basics", "EmptyBob");
+            assert_err!(
+                MoveModule::publish_module(
+                    RuntimeOrigin::signed(bob_addr_32.clone()),
+                    module,
+                    MAX_GAS_AMOUNT,
+                ),
+                Error::<Test>::InsufficientBalanceForGasFee
+            );
+
+            assert_eq!(Balances::free_balance(&bob_addr_32), INITIAL_BALANCE);
+        })
+}
+
+/// The user calling the MoveVM pays for the gas of an execution.
+#[test]
+fn execute_charges_used_gas() {
+    let (bob_addr_32, bob_addr_mv) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default()
+        .with_balances(vec![(bob_addr_32.clone(), INITIAL_BALANCE)])
+        .build()
+        .execute_with(|| {
+            let bundle = utils::read_bundle_from_project("basic_coin", "basic_coin");
+            assert_ok!(MoveModule::publish_module_bundle(
+                RuntimeOrigin::signed(bob_addr_32.clone()),
+                bundle,
+                MAX_GAS_AMOUNT,
+            ));
+
+            GasPrice::set(GAS_PRICE);
+
+            // publish_balance(account: &signer)
+            assert_ok!(MoveModule::execute_entry_function(
+                RuntimeOrigin::signed(bob_addr_32.clone()),
+                bob_addr_32.clone(),
+                b"BasicCoin".to_vec(),
+                b"publish_balance".to_vec(),
+                vec![],
+                vec![bcs::to_bytes(&bob_addr_mv).unwrap()],
+                MAX_GAS_AMOUNT,
+                EMPTY_CHEQUE,
+            ));
+
+            let charged = INITIAL_BALANCE - Balances::free_balance(&bob_addr_32);
+            assert!(charged > 0);
+            assert!(charged < Balance::from(MAX_GAS_AMOUNT) * GAS_PRICE);
+            assert_eq!(charged % GAS_PRICE, 0);
+        })
+}
+
+/// A failing execution gets reverted completely, including its gas fee.
+#[test]
+fn failing_execute_charges_no_gas_fee() {
+    let (bob_addr_32, bob_addr_mv) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default()
+        .with_balances(vec![(bob_addr_32.clone(), INITIAL_BALANCE)])
+        .build()
+        .execute_with(|| {
+            let bundle = utils::read_bundle_from_project("basic_coin", "basic_coin");
+            assert_ok!(MoveModule::publish_module_bundle(
+                RuntimeOrigin::signed(bob_addr_32.clone()),
+                bundle,
+                MAX_GAS_AMOUNT,
+            ));
+
+            // publish_balance(account: &signer)
+            let publish_balance = RuntimeCall::MoveModule(Call::execute_entry_function {
+                module_address: bob_addr_32.clone(),
+                module_name: b"BasicCoin".to_vec(),
+                function_name: b"publish_balance".to_vec(),
+                type_args: vec![],
+                args: vec![bcs::to_bytes(&bob_addr_mv).unwrap()],
+                gas_limit: MAX_GAS_AMOUNT,
+                cheque_limit: EMPTY_CHEQUE,
+            });
+            assert_ok!(publish_balance
+                .clone()
+                .dispatch(RuntimeOrigin::signed(bob_addr_32.clone())));
+
+            GasPrice::set(GAS_PRICE);
+            let balance = Balances::free_balance(&bob_addr_32);
+
+            // The balance has already been published, so the second call aborts. It is dispatched
+            // like a real extrinsic, so that all its changes get reverted.
+            let res = publish_balance.dispatch(RuntimeOrigin::signed(bob_addr_32.clone()));
+            assert!(verify_module_error_with_msg(res, "Aborted").unwrap());
+
+            assert_eq!(Balances::free_balance(&bob_addr_32), balance);
+        })
+}
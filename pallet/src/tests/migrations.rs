@@ -0,0 +1,128 @@
+//! Integration tests related to the storage migrations.
+
+use crate::{
+    migrations, mock::*, mock_utils as utils, no_type_args, script_transaction,
+    MultisigRequestsBySigner, VMStorage, VMStorageKeys,
+};
+
+use codec::Encode;
+use frame_support::{
+    assert_ok,
+    migrations::SteppedMigration,
+    traits::{GetStorageVersion, StorageVersion},
+    weights::WeightMeter,
+};
+use sp_core::hashing::blake2_128;
+
+// Runs all steps of a multi-block migration, like `pallet_migrations` would do.
+fn run_migration<M: SteppedMigration>() {
+    let mut cursor = None;
+    loop {
+        cursor = M::step(cursor, &mut WeightMeter::new()).expect("failed to run a migration step");
+        if cursor.is_none() {
+            break;
+        }
+    }
+}
+
+/// The migration to version 1 indexes all keys of the MoveVM storage.
+#[test]
+fn index_vm_storage_keys_migration_works() {
+    let bob_addr_native = utils::account::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(MoveModule::publish_module(
+            RuntimeOrigin::signed(bob_addr_native),
+            utils::read_module_from_project("move-basics", "EmptyBob"),
+            MAX_GAS_AMOUNT,
+        ));
+        // The standard library from the genesis and all published modules are indexed.
+        let index: Vec<_> = VMStorageKeys::<Test>::iter().collect();
+        assert_eq!(index.len(), VMStorage::<Test>::iter_keys().count());
+
+        // Restore the state before the upgrade.
+        let _ = VMStorageKeys::<Test>::clear(u32::MAX, None);
+        StorageVersion::new(0).put::<MoveModule>();
+
+        run_migration::<migrations::v1::IndexVMStorageKeys<Test>>();
+        assert_eq!(MoveModule::on_chain_storage_version(), 1);
+        assert_eq!(VMStorageKeys::<Test>::iter().collect::<Vec<_>>(), index);
+
+        // Running it again doesn't change anything.
+        run_migration::<migrations::v1::IndexVMStorageKeys<Test>>();
+        assert_eq!(VMStorageKeys::<Test>::iter().collect::<Vec<_>>(), index);
+    });
+}
+
+/// The migration to version 1 continues with the key its cursor points to.
+#[test]
+fn index_vm_storage_keys_migration_continues_at_cursor() {
+    ExtBuilder::default().build().execute_with(|| {
+        let _ = VMStorageKeys::<Test>::clear(u32::MAX, None);
+        StorageVersion::new(0).put::<MoveModule>();
+
+        // The cursor points to the second key in storage order.
+        let keys: Vec<_> = VMStorage::<Test>::iter_keys().collect();
+        assert!(keys.len() > 1);
+        let cursor = blake2_128(&keys[1].encode());
+
+        let cursor =
+            migrations::v1::IndexVMStorageKeys::<Test>::step(Some(cursor), &mut WeightMeter::new());
+        assert!(matches!(cursor, Ok(None)));
+        assert_eq!(MoveModule::on_chain_storage_version(), 1);
+        assert_eq!(VMStorageKeys::<Test>::iter_keys().count(), keys.len() - 1);
+    });
+}
+
+/// The migration to version 2 indexes all pending multi-signer requests per signer.
+#[test]
+fn index_multisig_requests_migration_works() {
+    const BALANCE: Balance = 80_000_000_000_000;
+    let (alice_addr_32, alice_addr_mv) = utils::account_n_address::<Test>(utils::ALICE_ADDR);
+    let (_, dave_addr_mv) = utils::account_n_address::<Test>(utils::DAVE_ADDR);
+    let (_, eve_addr_mv) = utils::account_n_address::<Test>(utils::EVE_ADDR);
+
+    ExtBuilder::default()
+        .with_balances(vec![(alice_addr_32.clone(), BALANCE)])
+        .build()
+        .execute_with(|| {
+            roll_to(1);
+
+            let script = utils::read_script_from_project("multiple-signers", "rent_apartment");
+            let transaction_bc = script_transaction!(
+                script,
+                no_type_args!(),
+                &alice_addr_mv,
+                &dave_addr_mv,
+                &eve_addr_mv,
+                &2u8
+            );
+            assert_ok!(MoveModule::execute(
+                RuntimeOrigin::signed(alice_addr_32),
+                transaction_bc,
+                MAX_GAS_AMOUNT,
+                BALANCE,
+            ));
+            // All three signers are indexed.
+            let index: Vec<_> = MultisigRequestsBySigner::<Test>::iter().collect();
+            assert_eq!(index.len(), 3);
+
+            // Restore the state before the upgrade.
+            let _ = MultisigRequestsBySigner::<Test>::clear(u32::MAX, None);
+            StorageVersion::new(1).put::<MoveModule>();
+
+            run_migration::<migrations::v2::IndexMultisigRequests<Test>>();
+            assert_eq!(MoveModule::on_chain_storage_version(), 2);
+            assert_eq!(
+                MultisigRequestsBySigner::<Test>::iter().collect::<Vec<_>>(),
+                index
+            );
+
+            // Running it again doesn't change anything.
+            run_migration::<migrations::v2::IndexMultisigRequests<Test>>();
+            assert_eq!(
+                MultisigRequestsBySigner::<Test>::iter().collect::<Vec<_>>(),
+                index
+            );
+        });
+}
@@ -1,5 +1,12 @@
-use crate::{mock::*, mock_utils as utils};
+use crate::{
+    api::{MoveApiModuleInfo, MoveApiValue},
+    mock::*,
+    mock_utils as utils, no_type_args, script_transaction,
+    storage::{split_key, StorageEntry},
+    VMStorage, VMStorageKeys,
+};
 
+use blake2::{Blake2s256, Digest};
 use frame_support::assert_ok;
 use move_core_types::{identifier::Identifier, language_storage::StructTag};
 
@@ -68,3 +75,268 @@ fn get_resource_non_existent() {
         assert_eq!(res, Ok(None));
     });
 }
+
+/// Test listing the resources of an account.
+#[test]
+fn list_resources_correct() {
+    let (bob_addr_32, bob_addr_mv) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+    let (alice_addr_32, alice_addr_mv) = utils::account_n_address::<Test>(utils::ALICE_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        let module = utils::read_module_from_project("get-resource", "Counter");
+        assert_ok!(MoveModule::publish_module(
+            RuntimeOrigin::signed(bob_addr_32.clone()),
+            module,
+            MAX_GAS_AMOUNT,
+        ));
+        assert_eq!(
+            MoveModule::list_resources(&alice_addr_32, None, None),
+            Ok(vec![])
+        );
+
+        let script = utils::read_script_from_project("get-resource", "create_counter");
+        let transaction_bc = script_transaction!(script, no_type_args!(), &alice_addr_mv);
+        assert_ok!(MoveModule::execute(
+            RuntimeOrigin::signed(alice_addr_32.clone()),
+            transaction_bc,
+            MAX_GAS_AMOUNT,
+            0,
+        ));
+
+        let tag = StructTag {
+            address: bob_addr_mv,
+            module: Identifier::new("Counter").unwrap(),
+            name: Identifier::new("Counter").unwrap(),
+            type_params: vec![],
+        };
+        let tag = bcs::to_bytes(&tag).unwrap();
+
+        // Modules are not listed as resources.
+        assert_eq!(
+            MoveModule::list_resources(&bob_addr_32, None, None),
+            Ok(vec![])
+        );
+        assert_eq!(
+            MoveModule::list_resources(&alice_addr_32, None, None),
+            Ok(vec![tag.clone()])
+        );
+
+        // Pagination.
+        assert_eq!(
+            MoveModule::list_resources(&alice_addr_32, None, Some(0)),
+            Ok(vec![])
+        );
+        assert_eq!(
+            MoveModule::list_resources(&alice_addr_32, Some(tag), None),
+            Ok(vec![])
+        );
+    });
+}
+
+/// Test paging through multiple resources of an account.
+#[test]
+fn list_resources_paginated() {
+    let (bob_addr_32, bob_addr_mv) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+    let (alice_addr_32, alice_addr_mv) = utils::account_n_address::<Test>(utils::ALICE_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        for module in ["Counter", "Guestbook"] {
+            assert_ok!(MoveModule::publish_module(
+                RuntimeOrigin::signed(bob_addr_32.clone()),
+                utils::read_module_from_project("get-resource", module),
+                MAX_GAS_AMOUNT,
+            ));
+        }
+        for script in ["create_counter", "create_guestbook"] {
+            let script = utils::read_script_from_project("get-resource", script);
+            let transaction_bc = script_transaction!(script, no_type_args!(), &alice_addr_mv);
+            assert_ok!(MoveModule::execute(
+                RuntimeOrigin::signed(alice_addr_32.clone()),
+                transaction_bc,
+                MAX_GAS_AMOUNT,
+                0,
+            ));
+        }
+
+        let mut expected: Vec<_> = ["Counter", "Guestbook"]
+            .into_iter()
+            .map(|name| {
+                let tag = StructTag {
+                    address: bob_addr_mv,
+                    module: Identifier::new(name).unwrap(),
+                    name: Identifier::new(name).unwrap(),
+                    type_params: vec![],
+                };
+                bcs::to_bytes(&tag).unwrap()
+            })
+            .collect();
+        // The index lists them in the order of their hashed storage keys.
+        let tags = MoveModule::list_resources(&alice_addr_32, None, None).unwrap();
+        let mut sorted = tags.clone();
+        sorted.sort();
+        expected.sort();
+        assert_eq!(sorted, expected);
+
+        assert_eq!(
+            MoveModule::list_resources(&alice_addr_32, None, Some(1)),
+            Ok(vec![tags[0].clone()])
+        );
+        assert_eq!(
+            MoveModule::list_resources(&alice_addr_32, Some(tags[0].clone()), Some(1)),
+            Ok(vec![tags[1].clone()])
+        );
+        assert_eq!(
+            MoveModule::list_resources(&alice_addr_32, Some(tags[1].clone()), Some(1)),
+            Ok(vec![])
+        );
+    });
+}
+
+/// Test that all keys written by the MoveVM can be decoded and are indexed per owner.
+#[test]
+fn storage_keys_round_trip() {
+    let (bob_addr_32, bob_addr_mv) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        let module = utils::read_module_from_project("get-resource", "Counter");
+        assert_ok!(MoveModule::publish_module(
+            RuntimeOrigin::signed(bob_addr_32.clone()),
+            module,
+            MAX_GAS_AMOUNT,
+        ));
+        let script = utils::read_script_from_project("get-resource", "create_counter");
+        let transaction_bc = script_transaction!(script, no_type_args!(), &bob_addr_mv);
+        assert_ok!(MoveModule::execute(
+            RuntimeOrigin::signed(bob_addr_32.clone()),
+            transaction_bc,
+            MAX_GAS_AMOUNT,
+            0,
+        ));
+
+        // Includes the standard library written at the genesis.
+        let mut modules = 0;
+        let mut resources = 0;
+        for (key, value) in VMStorage::<Test>::iter() {
+            let (owner, entry) = StorageEntry::from_key(&key).expect("undecodable key");
+            let (_, tag, path) = split_key(&key).unwrap();
+            let owner_32 = MoveModule::to_native_account(&owner).unwrap();
+            assert!(VMStorageKeys::<Test>::contains_key(
+                (owner_32.clone(), tag),
+                path
+            ));
+
+            // The backend finds the same value under the decoded entry.
+            match entry {
+                StorageEntry::Module(module_id) => {
+                    modules += 1;
+                    let bytecode = MoveModule::get_module(&owner_32, module_id.name().as_str());
+                    assert_eq!(bytecode, Ok(Some(value)));
+                }
+                StorageEntry::Resource(tag) => {
+                    resources += 1;
+                    let tag = bcs::to_bytes(&tag).unwrap();
+                    assert_eq!(MoveModule::get_resource(&owner_32, &tag), Ok(Some(value)));
+                }
+            }
+        }
+        assert!(modules > 1);
+        assert!(resources >= 1);
+        assert_eq!(
+            VMStorageKeys::<Test>::iter_keys().count(),
+            modules + resources
+        );
+    });
+}
+
+/// Test listing the modules of an account.
+#[test]
+fn list_modules_correct() {
+    let addr_native = utils::account::<Test>(utils::CAFE_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(MoveModule::list_modules(&addr_native, true), Ok(vec![]));
+
+        let module = utils::read_module_from_project("move-basics", "Empty");
+        assert_ok!(MoveModule::publish_module(
+            RuntimeOrigin::signed(addr_native.clone()),
+            module.clone(),
+            MAX_GAS_AMOUNT,
+        ));
+
+        assert_eq!(
+            MoveModule::list_modules(&addr_native, false),
+            Ok(vec![MoveApiModuleInfo {
+                name: "Empty".to_string(),
+                bytecode_hash: None,
+            }])
+        );
+        assert_eq!(
+            MoveModule::list_modules(&addr_native, true),
+            Ok(vec![MoveApiModuleInfo {
+                name: "Empty".to_string(),
+                bytecode_hash: Some(Blake2s256::digest(&module).into()),
+            }])
+        );
+
+        // The standard library can be listed like the modules of any other account.
+        let stdlib_addr_native = utils::account::<Test>(utils::PROHIBITED_ADDR);
+        let stdlib = MoveModule::list_modules(&stdlib_addr_native, true).unwrap();
+        assert!(!stdlib.is_empty());
+        assert!(stdlib.windows(2).all(|pair| pair[0].name < pair[1].name));
+        for info in stdlib {
+            let bytecode = MoveModule::get_module(&stdlib_addr_native, &info.name)
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                info.bytecode_hash,
+                Some(Blake2s256::digest(&bytecode).into())
+            );
+        }
+    });
+}
+
+/// Test getting a resource decoded with the layout of its struct.
+#[test]
+fn get_resource_value_correct() {
+    let (bob_addr_32, bob_addr_mv) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+    let (alice_addr_32, alice_addr_mv) = utils::account_n_address::<Test>(utils::ALICE_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        let module = utils::read_module_from_project("get-resource", "Counter");
+        assert_ok!(MoveModule::publish_module(
+            RuntimeOrigin::signed(bob_addr_32),
+            module,
+            MAX_GAS_AMOUNT,
+        ));
+
+        let tag = StructTag {
+            address: bob_addr_mv,
+            module: Identifier::new("Counter").unwrap(),
+            name: Identifier::new("Counter").unwrap(),
+            type_params: vec![],
+        };
+        let tag = bcs::to_bytes(&tag).unwrap();
+        assert_eq!(
+            MoveModule::get_resource_value(&alice_addr_32, &tag),
+            Ok(None)
+        );
+
+        let script = utils::read_script_from_project("get-resource", "create_counter");
+        let transaction_bc = script_transaction!(script, no_type_args!(), &alice_addr_mv);
+        assert_ok!(MoveModule::execute(
+            RuntimeOrigin::signed(alice_addr_32.clone()),
+            transaction_bc,
+            MAX_GAS_AMOUNT,
+            0,
+        ));
+
+        let count = MoveApiValue::Struct(vec![("value".to_string(), MoveApiValue::U64(0))]);
+        assert_eq!(
+            MoveModule::get_resource_value(&alice_addr_32, &tag),
+            Ok(Some(MoveApiValue::Struct(vec![(
+                "counter".to_string(),
+                count
+            )])))
+        );
+    });
+}
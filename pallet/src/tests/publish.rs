@@ -1,6 +1,16 @@
-use crate::{mock::*, mock_utils as utils, GasStrategy};
-
-use frame_support::assert_ok;
+use crate::{
+    api::MoveApiValue, mock::*, mock_utils as utils, no_type_args, script_transaction,
+    upgrade::UpgradePolicy, weight_info::WeightInfo, Call, Error, Event, GasStrategy,
+};
+
+use frame_support::{assert_err, assert_ok};
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::{ModuleId, StructTag},
+    vm_status::StatusCode,
+};
+use sp_runtime::traits::Dispatchable;
 
 /// Test that the module is published correctly.
 #[test]
@@ -172,10 +182,14 @@ fn raw_publish_module_dry_run() {
     ExtBuilder::default().build().execute_with(|| {
         let module = utils::read_module_from_project("using_stdlib_natives", "Vector");
 
-        let estimation =
-            MoveModule::raw_publish_module(&bob_addr_move, module.clone(), GasStrategy::DryRun)
-                .expect("failed to publish a module")
-                .gas_used as u32;
+        let estimation = MoveModule::raw_publish_module(
+            &bob_addr_move,
+            module.clone(),
+            GasStrategy::DryRun,
+            Default::default(),
+        )
+        .expect("failed to publish a module")
+        .gas_used as u32;
 
         let insufficient_gas = estimation - 1;
         let invalid_publish = MoveModule::publish_module(
@@ -203,10 +217,14 @@ fn raw_publish_bundle_dry_run() {
         let bundle =
             utils::read_bundle_from_project("using_stdlib_natives", "using_stdlib_natives");
 
-        let estimation =
-            MoveModule::raw_publish_bundle(&bob_addr_move, bundle.clone(), GasStrategy::DryRun)
-                .expect("failed to publish a bundle")
-                .gas_used as u32;
+        let estimation = MoveModule::raw_publish_bundle(
+            &bob_addr_move,
+            bundle.clone(),
+            GasStrategy::DryRun,
+            Default::default(),
+        )
+        .expect("failed to publish a bundle")
+        .gas_used as u32;
 
         let insufficient_gas = estimation - 1;
         let invalid_publish = MoveModule::publish_module(
@@ -279,3 +297,473 @@ fn publish_module_with_base58_address() {
             .unwrap();
     })
 }
+
+/// Test that an immutable module cannot be updated anymore.
+#[test]
+fn publish_module_fails_when_module_is_immutable() {
+    let bob_addr_native = utils::account::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        // Roll to first block in case of block based event checkings and processes.
+        roll_to(1);
+
+        let module = utils::read_module_from_project("move-basics", "EmptyBob");
+        let origin = RuntimeOrigin::signed(bob_addr_native.clone());
+
+        assert_ok!(MoveModule::publish_module(
+            origin.clone(),
+            module.clone(),
+            MAX_GAS_AMOUNT
+        ));
+        // Compatible updates are allowed by default.
+        assert_ok!(MoveModule::publish_module(
+            origin.clone(),
+            module.clone(),
+            MAX_GAS_AMOUNT
+        ));
+
+        assert_ok!(MoveModule::set_upgrade_policy(
+            origin.clone(),
+            b"EmptyBob".to_vec(),
+            UpgradePolicy::Immutable,
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::MoveModule(Event::<Test>::UpgradePolicyChanged {
+                who: bob_addr_native,
+                module: b"EmptyBob".to_vec(),
+                policy: UpgradePolicy::Immutable,
+            })
+        );
+
+        assert_err!(
+            MoveModule::publish_module(origin, module, MAX_GAS_AMOUNT),
+            Error::<Test>::ModuleImmutable
+        );
+    })
+}
+
+/// Test that a bundle containing an immutable module cannot be published.
+#[test]
+fn publish_bundle_fails_when_module_is_immutable() {
+    let bob_addr_native = utils::account::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        let bundle =
+            utils::read_bundle_from_project("using_stdlib_natives", "using_stdlib_natives");
+        let origin = RuntimeOrigin::signed(bob_addr_native);
+
+        assert_ok!(MoveModule::publish_module_bundle(
+            origin.clone(),
+            bundle.clone(),
+            MAX_GAS_AMOUNT
+        ));
+        assert_ok!(MoveModule::set_upgrade_policy(
+            origin.clone(),
+            b"Vector".to_vec(),
+            UpgradePolicy::Immutable,
+        ));
+
+        assert_err!(
+            MoveModule::publish_module_bundle(origin, bundle, MAX_GAS_AMOUNT),
+            Error::<Test>::ModuleImmutable
+        );
+    })
+}
+
+/// Test that upgrade policies can only be tightened.
+#[test]
+fn set_upgrade_policy_cannot_be_relaxed() {
+    let bob_addr_native = utils::account::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        let module = utils::read_module_from_project("move-basics", "EmptyBob");
+        let origin = RuntimeOrigin::signed(bob_addr_native);
+
+        assert_ok!(MoveModule::publish_module(
+            origin.clone(),
+            module,
+            MAX_GAS_AMOUNT
+        ));
+        assert_ok!(MoveModule::set_upgrade_policy(
+            origin.clone(),
+            b"EmptyBob".to_vec(),
+            UpgradePolicy::Immutable,
+        ));
+
+        assert_err!(
+            MoveModule::set_upgrade_policy(
+                origin,
+                b"EmptyBob".to_vec(),
+                UpgradePolicy::Compatible,
+            ),
+            Error::<Test>::UpgradePolicyNotAllowed
+        );
+    })
+}
+
+/// Test that upgrade policies can only be set for published modules.
+#[test]
+fn set_upgrade_policy_for_unknown_module_fails() {
+    let bob_addr_native = utils::account::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        assert_err!(
+            MoveModule::set_upgrade_policy(
+                RuntimeOrigin::signed(bob_addr_native.clone()),
+                b"EmptyBob".to_vec(),
+                UpgradePolicy::Immutable,
+            ),
+            Error::<Test>::ModuleNotFound
+        );
+
+        assert_err!(
+            MoveModule::set_upgrade_policy(
+                RuntimeOrigin::signed(bob_addr_native),
+                vec![0xff, 0xfe],
+                UpgradePolicy::Immutable,
+            ),
+            Error::<Test>::InvalidModuleName
+        );
+    })
+}
+
+/// Test that only the weight of the used gas is charged when publishing a module.
+#[test]
+fn publish_module_refunds_unused_gas() {
+    let (bob_addr_native, bob_addr_move) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        let module = utils::read_module_from_project("using_stdlib_natives", "Vector");
+
+        let gas_used = MoveModule::raw_publish_module(
+            &bob_addr_move,
+            module.clone(),
+            GasStrategy::DryRun,
+            Default::default(),
+        )
+        .expect("failed to publish a module")
+        .gas_used as u32;
+
+        let post_info = MoveModule::publish_module(
+            RuntimeOrigin::signed(bob_addr_native),
+            module,
+            MAX_GAS_AMOUNT,
+        )
+        .expect("failed to publish a module");
+
+        type Weights = <Test as crate::Config>::WeightInfo;
+        let weight = Weights::publish_module_generic(gas_used);
+        assert_eq!(post_info.actual_weight, Some(weight));
+        assert!(weight.ref_time() < Weights::publish_module_generic(MAX_GAS_AMOUNT).ref_time());
+    });
+}
+
+/// The recommended gas limit of the estimation is sufficient for the real extrinsic.
+#[test]
+fn publish_module_with_recommended_gas_limit() {
+    let bob_addr_native = utils::account::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        let module = utils::read_module_from_project("move-basics", "EmptyBob");
+
+        let estimation =
+            MoveModule::rpc_estimate_gas_publish_module(&bob_addr_native, module.clone())
+                .expect("failed to estimate the gas");
+        let gas_limit = estimation
+            .recommended_gas_limit
+            .expect("no recommended gas limit");
+        assert!(u64::from(gas_limit) >= estimation.gas_used);
+
+        // The estimation didn't publish anything.
+        assert_eq!(
+            MoveModule::get_module(&bob_addr_native, "EmptyBob"),
+            Ok(None)
+        );
+
+        assert_ok!(MoveModule::publish_module(
+            RuntimeOrigin::signed(bob_addr_native),
+            module,
+            gas_limit,
+        ));
+    })
+}
+
+/// There is no recommended gas limit for failing publications.
+#[test]
+fn estimate_failing_publication_without_gas_limit() {
+    let cafe_addr_native = utils::account::<Test>(utils::CAFE_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        // The module belongs to Bob.
+        let module = utils::read_module_from_project("move-basics", "EmptyBob");
+
+        let estimation = MoveModule::rpc_estimate_gas_publish_module(&cafe_addr_native, module)
+            .expect("failed to estimate the gas");
+        assert_eq!(estimation.recommended_gas_limit, None);
+    })
+}
+
+/// A bundle can be published and initialized within a single call.
+#[test]
+fn publish_bundle_and_execute_works() {
+    let (bob_addr_native, bob_addr_move) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        roll_to(1);
+
+        let bundle = utils::read_bundle_from_project("basic_coin", "basic_coin");
+        let script = utils::read_script_from_project("basic_coin", "publish_balance");
+        let transaction_bc = script_transaction!(script, no_type_args!(), &bob_addr_move);
+
+        assert_ok!(MoveModule::publish_bundle_and_execute(
+            RuntimeOrigin::signed(bob_addr_native.clone()),
+            bundle,
+            transaction_bc,
+            MAX_GAS_AMOUNT,
+            0,
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::MoveModule(Event::<Test>::ExecuteCalled {
+                who: vec![bob_addr_native.clone()]
+            })
+        );
+
+        let tag = StructTag {
+            address: bob_addr_move,
+            module: Identifier::new("BasicCoin").unwrap(),
+            name: Identifier::new("Balance").unwrap(),
+            type_params: vec![],
+        };
+        assert!(
+            MoveModule::get_resource(&bob_addr_native, &bcs::to_bytes(&tag).unwrap())
+                .unwrap()
+                .is_some()
+        );
+    })
+}
+
+/// The publication gets reverted if the initialization script fails.
+#[test]
+fn publish_bundle_and_execute_reverts_on_failure() {
+    let (bob_addr_native, bob_addr_move) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+    let (_, alice_addr_move) = utils::account_n_address::<Test>(utils::ALICE_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        let bundle = utils::read_bundle_from_project("basic_coin", "basic_coin");
+
+        // Minting fails, since Alice has no balance resource.
+        let script = utils::read_script_from_project("basic_coin", "mint_some");
+        let transaction_bc = script_transaction!(
+            script,
+            no_type_args!(),
+            &bob_addr_move,
+            &alice_addr_move,
+            &100u64
+        );
+        assert!(MoveModule::publish_bundle_and_execute(
+            RuntimeOrigin::signed(bob_addr_native.clone()),
+            bundle.clone(),
+            transaction_bc,
+            MAX_GAS_AMOUNT,
+            0,
+        )
+        .is_err());
+        assert_eq!(
+            MoveModule::get_module(&bob_addr_native, "BasicCoin"),
+            Ok(None)
+        );
+
+        // Only the publisher can sign the script.
+        let script = utils::read_script_from_project("basic_coin", "publish_balance");
+        let transaction_bc = script_transaction!(script, no_type_args!(), &alice_addr_move);
+        assert_err!(
+            MoveModule::publish_bundle_and_execute(
+                RuntimeOrigin::signed(bob_addr_native.clone()),
+                bundle,
+                transaction_bc,
+                MAX_GAS_AMOUNT,
+                0,
+            ),
+            Error::<Test>::UnexpectedUserSignature
+        );
+    })
+}
+
+/// A private `init_module` function gets executed when the module is published for the first time.
+#[test]
+fn publish_module_runs_init_module() {
+    let (bob_addr_native, bob_addr_move) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        let module = utils::read_module_from_project("get-resource", "Registry");
+
+        assert_ok!(MoveModule::publish_module(
+            RuntimeOrigin::signed(bob_addr_native.clone()),
+            module.clone(),
+            MAX_GAS_AMOUNT,
+        ));
+
+        let tag = StructTag {
+            address: bob_addr_move,
+            module: Identifier::new("Registry").unwrap(),
+            name: Identifier::new("Registry").unwrap(),
+            type_params: vec![],
+        };
+        assert_eq!(
+            MoveModule::get_resource_value(&bob_addr_native, &bcs::to_bytes(&tag).unwrap()),
+            Ok(Some(MoveApiValue::Struct(vec![(
+                "owner".to_string(),
+                MoveApiValue::Address(bob_addr_move.into_bytes())
+            )])))
+        );
+
+        // Running the function again would fail, since the resource already exists.
+        assert_ok!(MoveModule::publish_module(
+            RuntimeOrigin::signed(bob_addr_native),
+            module,
+            MAX_GAS_AMOUNT,
+        ));
+    })
+}
+
+fn registry_value(owner: AccountAddress) -> Result<Option<MoveApiValue>, Vec<u8>> {
+    let account = MoveModule::to_native_account(&owner).unwrap();
+    let tag = StructTag {
+        address: owner,
+        module: Identifier::new("Registry").unwrap(),
+        name: Identifier::new("Registry").unwrap(),
+        type_params: vec![],
+    };
+    MoveModule::get_resource_value(&account, &bcs::to_bytes(&tag).unwrap())
+}
+
+/// The estimation of a module includes its `init_module` function, without applying any changes.
+#[test]
+fn estimate_and_publish_module_with_init_module() {
+    let (bob_addr_native, bob_addr_move) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        let module = utils::read_module_from_project("get-resource", "Registry");
+
+        let estimation =
+            MoveModule::rpc_estimate_gas_publish_module(&bob_addr_native, module.clone())
+                .expect("failed to estimate the gas");
+        assert_eq!(estimation.vm_status_code, u64::from(StatusCode::EXECUTED));
+        let gas_limit = estimation
+            .recommended_gas_limit
+            .expect("no recommended gas limit");
+
+        // The estimation neither published the module nor executed the function.
+        assert_eq!(
+            MoveModule::get_module(&bob_addr_native, "Registry"),
+            Ok(None)
+        );
+        assert_eq!(registry_value(bob_addr_move), Ok(None));
+
+        // The recommended gas limit is the minimum, which covers the function as well. Running out
+        // of gas reverts the publication.
+        let publish_module = |gas_limit| {
+            RuntimeCall::MoveModule(Call::publish_module {
+                bytecode: module.clone(),
+                gas_limit,
+            })
+            .dispatch(RuntimeOrigin::signed(bob_addr_native.clone()))
+        };
+        assert!(verify_module_error_with_msg(publish_module(gas_limit - 1), "OutOfGas").unwrap());
+        assert_eq!(
+            MoveModule::get_module(&bob_addr_native, "Registry"),
+            Ok(None)
+        );
+
+        assert_ok!(publish_module(gas_limit));
+        assert!(registry_value(bob_addr_move).unwrap().is_some());
+    })
+}
+
+/// The estimation of a bundle includes its `init_module` functions, without applying any changes.
+#[test]
+fn estimate_and_publish_bundle_with_init_module() {
+    let (bob_addr_native, bob_addr_move) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        let bundle = utils::read_bundle_from_project("get-resource", "get-resource");
+
+        let estimation =
+            MoveModule::rpc_estimate_gas_publish_bundle(&bob_addr_native, bundle.clone())
+                .expect("failed to estimate the gas");
+        assert_eq!(estimation.vm_status_code, u64::from(StatusCode::EXECUTED));
+        let gas_limit = estimation
+            .recommended_gas_limit
+            .expect("no recommended gas limit");
+
+        // The estimation neither published the bundle nor executed the function.
+        assert_eq!(
+            MoveModule::get_module(&bob_addr_native, "Registry"),
+            Ok(None)
+        );
+        assert_eq!(registry_value(bob_addr_move), Ok(None));
+
+        assert_ok!(MoveModule::publish_module_bundle(
+            RuntimeOrigin::signed(bob_addr_native),
+            bundle,
+            gas_limit,
+        ));
+        assert!(registry_value(bob_addr_move).unwrap().is_some());
+    })
+}
+
+/// Private `init_module` functions are executed via `raw_init_module`, which relies on the MoveVM
+/// backend not checking the visibility of the function.
+#[test]
+fn execute_private_init_module_function() {
+    let (bob_addr_native, bob_addr_move) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        let module = utils::read_module_from_project("get-resource", "Registry");
+
+        // Publishing the module directly skips the function.
+        let vm_result = MoveModule::raw_publish_module(
+            &bob_addr_move,
+            module,
+            GasStrategy::Unmetered,
+            Default::default(),
+        )
+        .expect("failed to publish a module");
+        assert_eq!(vm_result.status_code, StatusCode::EXECUTED);
+        assert_eq!(registry_value(bob_addr_move), Ok(None));
+
+        let module_id = ModuleId::new(bob_addr_move, Identifier::new("Registry").unwrap());
+        let vm_result = MoveModule::raw_init_module(
+            &module_id,
+            &bob_addr_move,
+            GasStrategy::Unmetered,
+            Default::default(),
+        )
+        .expect("failed to execute the function");
+        assert_eq!(vm_result.status_code, StatusCode::EXECUTED);
+        assert_eq!(
+            registry_value(bob_addr_move),
+            Ok(Some(MoveApiValue::Struct(vec![(
+                "owner".to_string(),
+                MoveApiValue::Address(bob_addr_move.into_bytes())
+            )])))
+        );
+
+        // The function isn't an entry function, so users can't call it themselves.
+        assert_err!(
+            MoveModule::execute_entry_function(
+                RuntimeOrigin::signed(bob_addr_native.clone()),
+                bob_addr_native,
+                b"Registry".to_vec(),
+                b"init_module".to_vec(),
+                vec![],
+                vec![bcs::to_bytes(&bob_addr_move).unwrap()],
+                MAX_GAS_AMOUNT,
+                EMPTY_CHEQUE,
+            ),
+            Error::<Test>::FunctionNotEntry
+        );
+    })
+}
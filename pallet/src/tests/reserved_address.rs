@@ -0,0 +1,166 @@
+//! Integration tests related to reserved addresses and framework publishing.
+
+use crate::{mock::*, mock_utils as utils, Error, Event, ReservedAddresses};
+
+use frame_support::{assert_err, assert_ok, pallet_prelude::*};
+
+/// Only the `ReservedAddressOrigin` can manage reserved addresses.
+#[test]
+fn regular_user_reserve_address_fail() {
+    let bob_addr_native = utils::account::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        assert_err!(
+            MoveModule::add_reserved_address(
+                RuntimeOrigin::signed(bob_addr_native.clone()),
+                bob_addr_native.clone(),
+            ),
+            DispatchError::BadOrigin,
+        );
+        assert_err!(
+            MoveModule::publish_framework_bundle(
+                RuntimeOrigin::signed(bob_addr_native.clone()),
+                bob_addr_native,
+                utils::read_bundle_from_project("basic_coin", "basic_coin"),
+                1,
+            ),
+            DispatchError::BadOrigin,
+        );
+    });
+}
+
+/// Reserved addresses can be added and removed again.
+#[test]
+fn add_and_remove_reserved_address() {
+    let bob_addr_native = utils::account::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(MoveModule::add_reserved_address(
+            RuntimeOrigin::root(),
+            bob_addr_native.clone(),
+        ));
+        assert!(ReservedAddresses::<Test>::contains_key(&bob_addr_native));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::MoveModule(Event::<Test>::ReservedAddressAdded {
+                address: bob_addr_native.clone(),
+            })
+        );
+        assert_err!(
+            MoveModule::add_reserved_address(RuntimeOrigin::root(), bob_addr_native.clone()),
+            Error::<Test>::AddressAlreadyReserved
+        );
+
+        assert_ok!(MoveModule::remove_reserved_address(
+            RuntimeOrigin::root(),
+            bob_addr_native.clone(),
+        ));
+        assert!(!ReservedAddresses::<Test>::contains_key(&bob_addr_native));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::MoveModule(Event::<Test>::ReservedAddressRemoved {
+                address: bob_addr_native.clone(),
+            })
+        );
+        assert_err!(
+            MoveModule::remove_reserved_address(RuntimeOrigin::root(), bob_addr_native),
+            Error::<Test>::AddressNotReserved
+        );
+    });
+}
+
+/// Modules at a reserved address can only be published by the `ReservedAddressOrigin`.
+#[test]
+fn publish_at_reserved_address() {
+    let bob_addr_native = utils::account::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        let bundle = utils::read_bundle_from_project("basic_coin", "basic_coin");
+
+        // Publishing a framework requires the address to be reserved.
+        assert_err!(
+            MoveModule::publish_framework_bundle(
+                RuntimeOrigin::root(),
+                bob_addr_native.clone(),
+                bundle.clone(),
+                1,
+            ),
+            Error::<Test>::AddressNotReserved
+        );
+
+        assert_ok!(MoveModule::add_reserved_address(
+            RuntimeOrigin::root(),
+            bob_addr_native.clone(),
+        ));
+
+        assert_err!(
+            MoveModule::publish_module_bundle(
+                RuntimeOrigin::signed(bob_addr_native.clone()),
+                bundle.clone(),
+                MAX_GAS_AMOUNT,
+            ),
+            Error::<Test>::ReservedAddressNotAllowed
+        );
+        assert_err!(
+            MoveModule::publish_module(
+                RuntimeOrigin::signed(bob_addr_native.clone()),
+                utils::read_module_from_project("move-basics", "EmptyBob"),
+                MAX_GAS_AMOUNT,
+            ),
+            Error::<Test>::ReservedAddressNotAllowed
+        );
+
+        // The bundle must not contain more modules than the call is weighed for.
+        assert_err!(
+            MoveModule::publish_framework_bundle(
+                RuntimeOrigin::root(),
+                bob_addr_native.clone(),
+                bundle.clone(),
+                0,
+            ),
+            Error::<Test>::ModuleCountExceeded
+        );
+
+        assert_ok!(MoveModule::publish_framework_bundle(
+            RuntimeOrigin::root(),
+            bob_addr_native.clone(),
+            bundle,
+            1,
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::MoveModule(Event::<Test>::FrameworkPublished {
+                address: bob_addr_native,
+            })
+        );
+    });
+}
+
+/// Addresses which already hold modules or resources can't be reserved.
+#[test]
+fn reserve_occupied_address_fail() {
+    let bob_addr_native = utils::account::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(MoveModule::publish_module(
+            RuntimeOrigin::signed(bob_addr_native.clone()),
+            utils::read_module_from_project("move-basics", "EmptyBob"),
+            MAX_GAS_AMOUNT,
+        ));
+
+        assert_err!(
+            MoveModule::add_reserved_address(RuntimeOrigin::root(), bob_addr_native.clone()),
+            Error::<Test>::AddressNotEmpty
+        );
+        assert!(!ReservedAddresses::<Test>::contains_key(&bob_addr_native));
+
+        // The standard library address holds the standard library anyway.
+        assert_err!(
+            MoveModule::add_reserved_address(
+                RuntimeOrigin::root(),
+                utils::account::<Test>(utils::PROHIBITED_ADDR),
+            ),
+            Error::<Test>::StdlibAddressNotAllowed
+        );
+    });
+}
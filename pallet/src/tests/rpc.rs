@@ -0,0 +1,151 @@
+//! Integration tests related to the helpers of the RPC methods.
+
+use crate::{
+    api::{MoveApiMultisigRequest, MoveApiMultisigSigner},
+    mock::*,
+    mock_utils as utils, no_type_args, result, script_transaction, Error,
+};
+
+use frame_support::{assert_ok, pallet_prelude::*};
+use move_core_types::vm_status::StatusCode;
+
+/// Errors of the pallet can be decoded into their names.
+#[test]
+fn error_name_of_pallet_errors() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(
+            MoveModule::rpc_error_name(Error::<Test>::LinkerError.into()),
+            Some("LinkerError".to_string())
+        );
+        assert_eq!(
+            MoveModule::rpc_error_name(Error::<Test>::AddressNotReserved.into()),
+            Some("AddressNotReserved".to_string())
+        );
+
+        // Other errors are not decoded.
+        assert_eq!(MoveModule::rpc_error_name(DispatchError::BadOrigin), None);
+        assert_eq!(
+            MoveModule::rpc_error_name(pallet_balances::Error::<Test>::InsufficientBalance.into()),
+            None
+        );
+    });
+}
+
+/// Errors converted from a MoveVM status carry the original status code.
+#[test]
+fn vm_status_of_pallet_errors() {
+    ExtBuilder::default().build().execute_with(|| {
+        for status in [
+            StatusCode::LINKER_ERROR,
+            StatusCode::OUT_OF_GAS,
+            StatusCode::ABORTED,
+            StatusCode::UNKNOWN_STATUS,
+        ] {
+            let error = result::vm_error::<Test>(status);
+            assert_eq!(MoveModule::rpc_error_vm_status(error), Some(status.into()));
+            // The status code doesn't change the error's name.
+            assert_eq!(
+                MoveModule::rpc_error_name(error),
+                MoveModule::rpc_error_name(Error::<Test>::from(status).into())
+            );
+        }
+
+        // Errors raised by the pallet itself don't have any status code, even if they share
+        // the variant with a converted status code.
+        assert_eq!(
+            MoveModule::rpc_error_vm_status(Error::<Test>::LinkerError.into()),
+            None
+        );
+        // Errors of the pallet itself and of other pallets don't have any status code.
+        assert_eq!(
+            MoveModule::rpc_error_vm_status(Error::<Test>::AddressNotReserved.into()),
+            None
+        );
+        assert_eq!(
+            MoveModule::rpc_error_vm_status(Error::<Test>::UnknownKind.into()),
+            None
+        );
+        assert_eq!(
+            MoveModule::rpc_error_vm_status(DispatchError::BadOrigin),
+            None
+        );
+    });
+}
+
+/// Pending multi-signer requests can be queried by their call hash and by their signers.
+#[test]
+fn get_pending_multisig_requests() {
+    const BALANCE: Balance = 80_000_000_000_000;
+    let bob_addr_32 = utils::account::<Test>(utils::BOB_ADDR);
+    let (alice_addr_32, alice_addr_mv) = utils::account_n_address::<Test>(utils::ALICE_ADDR);
+    let (dave_addr_32, dave_addr_mv) = utils::account_n_address::<Test>(utils::DAVE_ADDR);
+    let (eve_addr_32, eve_addr_mv) = utils::account_n_address::<Test>(utils::EVE_ADDR);
+
+    ExtBuilder::default()
+        .with_balances(vec![(alice_addr_32.clone(), BALANCE)])
+        .build()
+        .execute_with(|| {
+            roll_to(1);
+
+            let script = utils::read_script_from_project("multiple-signers", "rent_apartment");
+            let transaction_bc = script_transaction!(
+                script,
+                no_type_args!(),
+                &alice_addr_mv,
+                &dave_addr_mv,
+                &eve_addr_mv,
+                &2u8
+            );
+            let call_hash = MoveModule::transaction_bc_call_hash(&transaction_bc[..]);
+            assert_eq!(MoveModule::rpc_get_multisig_request(call_hash), None);
+
+            assert_ok!(MoveModule::execute(
+                RuntimeOrigin::signed(alice_addr_32.clone()),
+                transaction_bc,
+                MAX_GAS_AMOUNT,
+                BALANCE,
+            ));
+
+            let signer = |account: &AccountId32, approved, cheque_limit| MoveApiMultisigSigner {
+                account: account.clone(),
+                approved,
+                cheque_limit,
+            };
+            let mut signers = vec![
+                signer(&alice_addr_32, true, BALANCE),
+                signer(&dave_addr_32, false, 0),
+                signer(&eve_addr_32, false, 0),
+            ];
+            signers.sort_by(|a, b| a.account.cmp(&b.account));
+            let request = MoveApiMultisigRequest {
+                call_hash,
+                signers,
+                expires_at: Some(6),
+            };
+
+            assert_eq!(
+                MoveModule::rpc_get_multisig_request(call_hash),
+                Some(request.clone())
+            );
+            assert_eq!(
+                MoveModule::rpc_pending_multisig_requests_for(eve_addr_32.clone()),
+                vec![request]
+            );
+            assert_eq!(
+                MoveModule::rpc_pending_multisig_requests_for(bob_addr_32),
+                vec![]
+            );
+
+            // Cancelled requests aren't pending for any of their signers anymore.
+            assert_ok!(MoveModule::cancel_multisig_request(
+                RuntimeOrigin::signed(dave_addr_32.clone()),
+                call_hash
+            ));
+            for account in [alice_addr_32, dave_addr_32, eve_addr_32] {
+                assert_eq!(
+                    MoveModule::rpc_pending_multisig_requests_for(account),
+                    vec![]
+                );
+            }
+        });
+}
@@ -1,5 +1,7 @@
 use crate::{
-    mock::*, mock_utils as utils, no_type_args, script_transaction, Error, Event, MultisigStorage,
+    mock::*, mock_utils as utils, no_type_args, script_transaction, signer::Signature,
+    weight_info::WeightInfo, ChoreOnIdleStorage, Error, Event, MultisigRequestsBySigner,
+    MultisigStorage,
 };
 
 use frame_support::{
@@ -369,7 +371,7 @@ fn signer_after_all_possible_vectors_fails() {
         ];
 
         let result = execute_script(&bob_addr_32, script, params, type_args);
-        assert_err!(result, Error::<Test>::InvalidMainFunctionSignature);
+        assert!(verify_module_error_with_msg(result, "InvalidMainFunctionSignature").unwrap());
     })
 }
 
@@ -389,7 +391,7 @@ fn script_with_vector_containing_signer_fails() {
         let params: Vec<&[u8]> = vec![&v_addr];
 
         let result = execute_script(&bob_addr_32, script, params, type_args);
-        assert_err!(result, Error::<Test>::InvalidMainFunctionSignature);
+        assert!(verify_module_error_with_msg(result, "InvalidMainFunctionSignature").unwrap());
     })
 }
 
@@ -533,6 +535,7 @@ fn multiple_signers_in_multisig_script_works() {
 
             // Multisig entry in storage should be cleaned up again after executing it.
             assert!(MultisigStorage::<Test>::try_get(call_hash).is_err());
+            assert_eq!(MultisigRequestsBySigner::<Test>::iter().count(), 0);
 
             // Remaining funds should be unlocked again.
             assert_ok!(ensure_can_withdraw(&alice_addr_32, CHANGE));
@@ -615,6 +618,9 @@ fn verify_old_multi_signer_requests_getting_removed() {
             roll_to(5);
             assert!(MultisigStorage::<Test>::try_get(call_hash).is_ok());
 
+            assert!(ensure_can_withdraw(&alice_addr_32, BALANCE).is_err());
+            assert!(ensure_can_withdraw(&dave_addr_32, BALANCE).is_err());
+
             // One more block forward and it shall be removed!
             roll_to(6);
             assert!(MultisigStorage::<Test>::try_get(call_hash).is_err());
@@ -625,6 +631,11 @@ fn verify_old_multi_signer_requests_getting_removed() {
                 })
             );
 
+            // The funds of Alice and Dave are unlocked again.
+            assert_ok!(ensure_can_withdraw(&alice_addr_32, BALANCE));
+            assert_ok!(ensure_can_withdraw(&dave_addr_32, BALANCE));
+            assert_eq!(MultisigRequestsBySigner::<Test>::iter().count(), 0);
+
             // If Eve now tries to sign that multi-signer request, a new request will be created.
             assert_ok!(MoveModule::execute(
                 RuntimeOrigin::signed(eve_addr_32.clone()),
@@ -726,3 +737,169 @@ fn insufficient_cheque_limit_aborts_the_multisig_script_works() {
             ));
         })
 }
+
+/// Any signer can cancel a pending multi-signer request, which unlocks all funds again.
+#[test]
+fn cancel_multisig_request_works() {
+    const BALANCE: Balance = 80_000_000_000_000;
+    let bob_addr_32 = utils::account::<Test>(utils::BOB_ADDR);
+    let (alice_addr_32, alice_addr_mv) = utils::account_n_address::<Test>(utils::ALICE_ADDR);
+    let (dave_addr_32, dave_addr_mv) = utils::account_n_address::<Test>(utils::DAVE_ADDR);
+    let (eve_addr_32, eve_addr_mv) = utils::account_n_address::<Test>(utils::EVE_ADDR);
+
+    ExtBuilder::default()
+        .with_balances(vec![
+            (bob_addr_32.clone(), BALANCE),
+            (alice_addr_32.clone(), BALANCE),
+            (dave_addr_32.clone(), BALANCE),
+            (eve_addr_32.clone(), BALANCE),
+        ])
+        .build()
+        .execute_with(|| {
+            // Roll to first block in case of block based event checkings and processes.
+            roll_to(1);
+
+            let script = utils::read_script_from_project("multiple-signers", "rent_apartment");
+            let transaction_bc = script_transaction!(
+                script,
+                no_type_args!(),
+                &alice_addr_mv,
+                &dave_addr_mv,
+                &eve_addr_mv,
+                &2u8
+            );
+            let call_hash = MoveModule::transaction_bc_call_hash(&transaction_bc[..]);
+
+            // Alice starts the multi-signer request and locks her funds.
+            assert_ok!(MoveModule::execute(
+                RuntimeOrigin::signed(alice_addr_32.clone()),
+                transaction_bc.clone(),
+                MAX_GAS_AMOUNT,
+                BALANCE,
+            ));
+            assert!(ensure_can_withdraw(&alice_addr_32, BALANCE).is_err());
+            assert!(ChoreOnIdleStorage::<Test>::contains_key(6));
+            // The request is indexed for all signers, including the ones who haven't signed yet.
+            for signer in [&alice_addr_32, &dave_addr_32, &eve_addr_32] {
+                assert!(MultisigRequestsBySigner::<Test>::contains_key(
+                    signer, call_hash
+                ));
+            }
+
+            // Bob is not one of the signers.
+            assert_err!(
+                MoveModule::cancel_multisig_request(
+                    RuntimeOrigin::signed(bob_addr_32.clone()),
+                    call_hash
+                ),
+                Error::<Test>::UnexpectedUserSignature
+            );
+
+            // Dave hasn't signed yet, but he can still cancel the request. Only the three signers
+            // of the request are charged for.
+            let post_info = MoveModule::cancel_multisig_request(
+                RuntimeOrigin::signed(dave_addr_32.clone()),
+                call_hash,
+            )
+            .expect("failed to cancel the request");
+            assert_eq!(
+                post_info.actual_weight,
+                Some(<Test as crate::Config>::WeightInfo::cancel_multisig_request(3))
+            );
+            assert_eq!(
+                last_event(),
+                RuntimeEvent::MoveModule(Event::<Test>::MultiSignRequestCancelled {
+                    who: dave_addr_32.clone(),
+                    call: call_hash,
+                })
+            );
+            assert!(MultisigStorage::<Test>::try_get(call_hash).is_err());
+            assert!(!ChoreOnIdleStorage::<Test>::contains_key(6));
+            assert_eq!(MultisigRequestsBySigner::<Test>::iter().count(), 0);
+            assert_ok!(ensure_can_withdraw(&alice_addr_32, BALANCE));
+
+            assert_err!(
+                MoveModule::cancel_multisig_request(RuntimeOrigin::signed(dave_addr_32), call_hash),
+                Error::<Test>::MultisigRequestNotFound
+            );
+        })
+}
+
+/// A signer can revoke the signature of a pending multi-signer request.
+#[test]
+fn revoke_multisig_signature_works() {
+    const BALANCE: Balance = 80_000_000_000_000;
+    let bob_addr_32 = utils::account::<Test>(utils::BOB_ADDR);
+    let (alice_addr_32, alice_addr_mv) = utils::account_n_address::<Test>(utils::ALICE_ADDR);
+    let (dave_addr_32, dave_addr_mv) = utils::account_n_address::<Test>(utils::DAVE_ADDR);
+    let (eve_addr_32, eve_addr_mv) = utils::account_n_address::<Test>(utils::EVE_ADDR);
+
+    ExtBuilder::default()
+        .with_balances(vec![
+            (bob_addr_32.clone(), BALANCE),
+            (alice_addr_32.clone(), BALANCE),
+            (dave_addr_32.clone(), BALANCE),
+            (eve_addr_32.clone(), BALANCE),
+        ])
+        .build()
+        .execute_with(|| {
+            // Roll to first block in case of block based event checkings and processes.
+            roll_to(1);
+
+            let script = utils::read_script_from_project("multiple-signers", "rent_apartment");
+            let transaction_bc = script_transaction!(
+                script,
+                no_type_args!(),
+                &alice_addr_mv,
+                &dave_addr_mv,
+                &eve_addr_mv,
+                &2u8
+            );
+            let call_hash = MoveModule::transaction_bc_call_hash(&transaction_bc[..]);
+
+            for signer in [&alice_addr_32, &dave_addr_32] {
+                assert_ok!(MoveModule::execute(
+                    RuntimeOrigin::signed(signer.clone()),
+                    transaction_bc.clone(),
+                    MAX_GAS_AMOUNT,
+                    BALANCE,
+                ));
+            }
+            assert!(ensure_can_withdraw(&alice_addr_32, BALANCE).is_err());
+
+            // Bob is not one of the signers, and Eve hasn't signed yet.
+            assert_err!(
+                MoveModule::revoke_multisig_signature(
+                    RuntimeOrigin::signed(bob_addr_32),
+                    call_hash
+                ),
+                Error::<Test>::UnexpectedUserSignature
+            );
+            assert_err!(
+                MoveModule::revoke_multisig_signature(
+                    RuntimeOrigin::signed(eve_addr_32),
+                    call_hash
+                ),
+                Error::<Test>::SignatureNotApproved
+            );
+
+            assert_ok!(MoveModule::revoke_multisig_signature(
+                RuntimeOrigin::signed(alice_addr_32.clone()),
+                call_hash
+            ));
+            assert_eq!(
+                last_event(),
+                RuntimeEvent::MoveModule(Event::<Test>::MultisigSignatureRevoked {
+                    who: alice_addr_32.clone(),
+                    call: call_hash,
+                })
+            );
+            assert_ok!(ensure_can_withdraw(&alice_addr_32, BALANCE));
+            assert!(ensure_can_withdraw(&dave_addr_32, BALANCE).is_err());
+
+            // The request is still pending, only without Alice's signature.
+            let request = MultisigStorage::<Test>::get(call_hash).unwrap();
+            assert_eq!(request[&alice_addr_32].signature, Signature::Missing);
+            assert_eq!(request[&dave_addr_32].signature, Signature::Approved);
+        })
+}
@@ -0,0 +1,126 @@
+//! Integration tests related to the script execution simulation.
+
+use crate::{
+    api::{MoveApiBalanceChange, MoveApiStorageChange},
+    mock::*,
+    mock_utils as utils, no_type_args, script_transaction,
+};
+
+use frame_support::assert_ok;
+use move_core_types::vm_status::StatusCode;
+
+/// Simulating a script reports the balance changes without applying them.
+#[test]
+fn simulate_transfer_reports_balance_changes() {
+    const AMOUNT: u128 = 100;
+
+    let (alice_addr_32, alice_addr_mv) = utils::account_n_address::<Test>(utils::ALICE_ADDR);
+    let (bob_addr_32, bob_addr_mv) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default()
+        .with_balances(vec![(alice_addr_32.clone(), 10_000)])
+        .build()
+        .execute_with(|| {
+            let ini_blnc_alice = Balances::free_balance(&alice_addr_32);
+            let ini_blnc_bob = Balances::free_balance(&bob_addr_32);
+
+            let script = utils::read_script_from_project("balance", "single_transfer");
+            let transaction_bc = script_transaction!(
+                script,
+                no_type_args!(),
+                &alice_addr_mv,
+                &bob_addr_mv,
+                &AMOUNT
+            );
+
+            let simulation = MoveModule::rpc_simulate_execute_script(transaction_bc)
+                .expect("failed to simulate the script");
+            assert_eq!(
+                simulation.estimation.vm_status_code,
+                u64::from(StatusCode::EXECUTED)
+            );
+
+            let mut changes = simulation.balance_changes;
+            changes.sort_by_key(|(account, _)| account != &alice_addr_32);
+            assert_eq!(
+                changes,
+                vec![
+                    (
+                        alice_addr_32.clone(),
+                        MoveApiBalanceChange::Withdrawn(AMOUNT)
+                    ),
+                    (bob_addr_32.clone(), MoveApiBalanceChange::Deposited(AMOUNT)),
+                ]
+            );
+
+            // Nothing has been applied.
+            assert_eq!(Balances::free_balance(&alice_addr_32), ini_blnc_alice);
+            assert_eq!(Balances::free_balance(&bob_addr_32), ini_blnc_bob);
+        })
+}
+
+/// Simulating a script reports the storage changes without applying them.
+#[test]
+fn simulate_script_reports_write_set() {
+    let (bob_addr_32, _) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+    let (alice_addr_32, alice_addr_mv) = utils::account_n_address::<Test>(utils::ALICE_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        let module = utils::read_module_from_project("get-resource", "Counter");
+        assert_ok!(MoveModule::publish_module(
+            RuntimeOrigin::signed(bob_addr_32),
+            module,
+            MAX_GAS_AMOUNT,
+        ));
+
+        let script = utils::read_script_from_project("get-resource", "create_counter");
+        let transaction_bc = script_transaction!(script, no_type_args!(), &alice_addr_mv);
+
+        let simulation = MoveModule::rpc_simulate_execute_script(transaction_bc.clone())
+            .expect("failed to simulate the script");
+        assert_eq!(simulation.write_set.len(), 1);
+        assert_eq!(
+            simulation.write_set[0].change,
+            MoveApiStorageChange::Added(vec![0, 0, 0, 0, 0, 0, 0, 0])
+        );
+        assert!(simulation.balance_changes.is_empty());
+        assert!(simulation.events.is_empty());
+
+        // The simulation didn't store anything, so the real execution leads to the same result.
+        assert_ok!(MoveModule::execute(
+            RuntimeOrigin::signed(alice_addr_32),
+            transaction_bc,
+            MAX_GAS_AMOUNT,
+            0,
+        ));
+    })
+}
+
+/// Signers can't transfer more than their free balance within a simulation.
+#[test]
+fn simulate_transfer_beyond_free_balance_fails() {
+    let (alice_addr_32, alice_addr_mv) = utils::account_n_address::<Test>(utils::ALICE_ADDR);
+    let (_, bob_addr_mv) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default()
+        .with_balances(vec![(alice_addr_32.clone(), 10_000)])
+        .build()
+        .execute_with(|| {
+            let script = utils::read_script_from_project("balance", "single_transfer");
+            let transaction_bc = script_transaction!(
+                script,
+                no_type_args!(),
+                &alice_addr_mv,
+                &bob_addr_mv,
+                &20_000u128
+            );
+
+            let simulation = MoveModule::rpc_simulate_execute_script(transaction_bc)
+                .expect("failed to simulate the script");
+            assert_ne!(
+                simulation.estimation.vm_status_code,
+                u64::from(StatusCode::EXECUTED)
+            );
+            assert!(simulation.balance_changes.is_empty());
+        })
+}
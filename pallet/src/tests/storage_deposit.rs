@@ -0,0 +1,238 @@
+//! Integration tests related to storage deposits for the MoveVM storage.
+
+use crate::{
+    mock::*, mock_utils as utils, no_type_args, script_transaction, Error, Event, StorageDeposits,
+    VMStorage,
+};
+
+use frame_support::{assert_err, assert_ok};
+
+const DEPOSIT_PER_BYTE: Balance = 1;
+const DEPOSIT_PER_ITEM: Balance = 1_000;
+
+fn set_deposits() {
+    DepositPerByte::set(DEPOSIT_PER_BYTE);
+    DepositPerItem::set(DEPOSIT_PER_ITEM);
+}
+
+/// Publishing a module reserves a deposit for the stored bytecode.
+#[test]
+fn publish_module_reserves_storage_deposit() {
+    let bob_addr_32 = utils::account::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default()
+        .with_balances(vec![(bob_addr_32.clone(), 1_000_000)])
+        .build()
+        .execute_with(|| {
+            set_deposits();
+
+            let module = utils::read_module_from_project("move-basics", "EmptyBob");
+            assert_ok!(MoveModule::publish_module(
+                RuntimeOrigin::signed(bob_addr_32.clone()),
+                module.clone(),
+                MAX_GAS_AMOUNT,
+            ));
+
+            let deposit = StorageDeposits::<Test>::get(&bob_addr_32, &bob_addr_32);
+            assert_eq!(deposit.items, 1);
+            assert!(deposit.bytes > module.len() as u64);
+            assert_eq!(
+                deposit.amount,
+                deposit.bytes as Balance * DEPOSIT_PER_BYTE + DEPOSIT_PER_ITEM
+            );
+            assert_eq!(Balances::reserved_balance(&bob_addr_32), deposit.amount);
+
+            // Updating a module with the same size won't change the deposit.
+            assert_ok!(MoveModule::publish_module(
+                RuntimeOrigin::signed(bob_addr_32.clone()),
+                module,
+                MAX_GAS_AMOUNT,
+            ));
+            assert_eq!(
+                StorageDeposits::<Test>::get(&bob_addr_32, &bob_addr_32),
+                deposit
+            );
+            assert_eq!(Balances::reserved_balance(&bob_addr_32), deposit.amount);
+        })
+}
+
+/// Publishing fails if the storage deposit cannot be reserved.
+#[test]
+fn publish_module_without_storage_deposit_fails() {
+    let bob_addr_32 = utils::account::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default().build().execute_with(|| {
+        set_deposits();
+
+        let module = utils::read_module_from_project("move-basics", "EmptyBob");
+        assert_err!(
+            MoveModule::publish_module(
+                RuntimeOrigin::signed(bob_addr_32.clone()),
+                module,
+                MAX_GAS_AMOUNT,
+            ),
+            Error::<Test>::InsufficientStorageDeposit
+        );
+
+        assert_eq!(
+            StorageDeposits::<Test>::get(&bob_addr_32, &bob_addr_32),
+            Default::default()
+        );
+    })
+}
+
+/// Resources created during an execution are covered by the storage deposit of their owner.
+#[test]
+fn execute_reserves_storage_deposit() {
+    let (bob_addr_32, bob_addr_mv) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default()
+        .with_balances(vec![(bob_addr_32.clone(), 1_000_000)])
+        .build()
+        .execute_with(|| {
+            set_deposits();
+
+            let bundle = utils::read_bundle_from_project("basic_coin", "basic_coin");
+            assert_ok!(MoveModule::publish_module_bundle(
+                RuntimeOrigin::signed(bob_addr_32.clone()),
+                bundle,
+                MAX_GAS_AMOUNT,
+            ));
+            let deposit = StorageDeposits::<Test>::get(&bob_addr_32, &bob_addr_32);
+
+            // publish_balance(account: &signer)
+            assert_ok!(MoveModule::execute_entry_function(
+                RuntimeOrigin::signed(bob_addr_32.clone()),
+                bob_addr_32.clone(),
+                b"BasicCoin".to_vec(),
+                b"publish_balance".to_vec(),
+                vec![],
+                vec![bcs::to_bytes(&bob_addr_mv).unwrap()],
+                MAX_GAS_AMOUNT,
+                EMPTY_CHEQUE,
+            ));
+
+            let new_deposit = StorageDeposits::<Test>::get(&bob_addr_32, &bob_addr_32);
+            assert_eq!(new_deposit.items, deposit.items + 1);
+            assert!(new_deposit.amount > deposit.amount);
+            assert_eq!(Balances::reserved_balance(&bob_addr_32), new_deposit.amount);
+        })
+}
+
+/// Growing the storage of another account is covered by the signer, not by the owner, and
+/// shrinking it again refunds the signer.
+#[test]
+fn execute_charges_storage_deposit_for_other_accounts_to_the_signer() {
+    let (bob_addr_32, bob_addr_mv) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+    let (alice_addr_32, alice_addr_mv) = utils::account_n_address::<Test>(utils::ALICE_ADDR);
+
+    ExtBuilder::default()
+        .with_balances(vec![
+            (bob_addr_32.clone(), 1_000_000),
+            (alice_addr_32.clone(), 1_000_000),
+        ])
+        .build()
+        .execute_with(|| {
+            set_deposits();
+
+            let module = utils::read_module_from_project("get-resource", "Guestbook");
+            assert_ok!(MoveModule::publish_module(
+                RuntimeOrigin::signed(bob_addr_32.clone()),
+                module,
+                MAX_GAS_AMOUNT,
+            ));
+            let script = utils::read_script_from_project("get-resource", "create_guestbook");
+            assert_ok!(MoveModule::execute(
+                RuntimeOrigin::signed(bob_addr_32.clone()),
+                script_transaction!(script, no_type_args!(), &bob_addr_mv),
+                MAX_GAS_AMOUNT,
+                0,
+            ));
+            let bob_deposit = StorageDeposits::<Test>::get(&bob_addr_32, &bob_addr_32);
+
+            // Alice adds her address to the guestbook stored under Bob's address.
+            let script = utils::read_script_from_project("get-resource", "sign_guestbook");
+            assert_ok!(MoveModule::execute(
+                RuntimeOrigin::signed(alice_addr_32.clone()),
+                script_transaction!(script, no_type_args!(), &alice_addr_mv, &bob_addr_mv),
+                MAX_GAS_AMOUNT,
+                0,
+            ));
+
+            assert_eq!(
+                StorageDeposits::<Test>::get(&bob_addr_32, &bob_addr_32),
+                bob_deposit
+            );
+            assert_eq!(Balances::reserved_balance(&bob_addr_32), bob_deposit.amount);
+
+            // Alice's deposit is recorded for the storage of Bob.
+            let alice_deposit = StorageDeposits::<Test>::get(&bob_addr_32, &alice_addr_32);
+            assert_eq!(alice_deposit.items, 0);
+            assert!(alice_deposit.bytes >= AccountAddress::LENGTH as u64);
+            assert_eq!(
+                alice_deposit.amount,
+                alice_deposit.bytes as Balance * DEPOSIT_PER_BYTE
+            );
+            assert_eq!(
+                Balances::reserved_balance(&alice_addr_32),
+                alice_deposit.amount
+            );
+
+            // Bob clears his guestbook, which releases the deposit paid by Alice.
+            let script = utils::read_script_from_project("get-resource", "clear_guestbook");
+            assert_ok!(MoveModule::execute(
+                RuntimeOrigin::signed(bob_addr_32.clone()),
+                script_transaction!(script, no_type_args!(), &bob_addr_mv),
+                MAX_GAS_AMOUNT,
+                0,
+            ));
+
+            assert!(!StorageDeposits::<Test>::contains_key(
+                &bob_addr_32,
+                &alice_addr_32
+            ));
+            assert_eq!(Balances::reserved_balance(&alice_addr_32), 0);
+            let bob_deposit = StorageDeposits::<Test>::get(&bob_addr_32, &bob_addr_32);
+            assert_eq!(Balances::reserved_balance(&bob_addr_32), bob_deposit.amount);
+        })
+}
+
+/// The tracked storage usage matches the keys and values actually written by the MoveVM.
+#[test]
+fn storage_usage_matches_the_written_entries() {
+    let (bob_addr_32, bob_addr_mv) = utils::account_n_address::<Test>(utils::BOB_ADDR);
+
+    ExtBuilder::default()
+        .with_balances(vec![(bob_addr_32.clone(), 1_000_000)])
+        .build()
+        .execute_with(|| {
+            set_deposits();
+
+            let bundle = utils::read_bundle_from_project("basic_coin", "basic_coin");
+            assert_ok!(MoveModule::publish_module_bundle(
+                RuntimeOrigin::signed(bob_addr_32.clone()),
+                bundle,
+                MAX_GAS_AMOUNT,
+            ));
+            let script = utils::read_script_from_project("basic_coin", "publish_balance");
+            assert_ok!(MoveModule::execute(
+                RuntimeOrigin::signed(bob_addr_32.clone()),
+                script_transaction!(script, no_type_args!(), &bob_addr_mv),
+                MAX_GAS_AMOUNT,
+                0,
+            ));
+
+            let (mut bytes, mut items) = (0, 0);
+            for (key, value) in VMStorage::<Test>::iter() {
+                if key.starts_with(bob_addr_mv.as_ref()) {
+                    bytes += (key.len() + value.len()) as u64;
+                    items += 1;
+                }
+            }
+
+            let deposit = StorageDeposits::<Test>::get(&bob_addr_32, &bob_addr_32);
+            assert!(items > 0);
+            assert_eq!(deposit.items, items);
+            assert_eq!(deposit.bytes, bytes);
+        })
+}
@@ -0,0 +1,29 @@
+//! Module upgrade policies, which allow publishers to give guarantees about their published code.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::RuntimeDebug;
+use scale_info::TypeInfo;
+
+/// Upgrade policy of a published module.
+///
+/// There is no policy for arbitrary updates: publishing a module which already exists always runs
+/// the MoveVM's compatibility check, which rejects incompatible updates with
+/// `BackwardIncompatibleModuleUpdate`. The backend offers no way to skip that check, so an
+/// `Arbitrary` policy would behave exactly like `Compatible`.
+#[derive(
+    Clone, Copy, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub enum UpgradePolicy {
+    /// Module can be updated with backward compatible changes.
+    #[default]
+    Compatible,
+    /// Module cannot be updated anymore.
+    Immutable,
+}
+
+impl UpgradePolicy {
+    /// Checks whether the policy can be changed to the given one. Policies can only be tightened.
+    pub fn can_change_to(&self, policy: &UpgradePolicy) -> bool {
+        *self == UpgradePolicy::Compatible || *policy == UpgradePolicy::Immutable
+    }
+}
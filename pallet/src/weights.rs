@@ -6,6 +6,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `michaeleberhardts-MacBook-Pro.local`, CPU: `<UNKNOWN>`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
+//!
+//! Weights marked as PLACEHOLDER haven't been benchmarked yet. They need to be regenerated with
+//! the command below before being used in production.
 
 // Executed Command:
 // ./target/release/node-template
@@ -84,4 +87,86 @@ impl<T: frame_system::Config> crate::weight_info::WeightInfo for SubstrateWeight
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// PLACEHOLDER: only the storage accesses are accounted for.
+	/// Storage: `MoveModule::VMStorage` (r:1 w:0)
+	/// Proof: `MoveModule::VMStorage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `MoveModule::ModuleUpgradePolicy` (r:1 w:1)
+	/// Proof: `MoveModule::ModuleUpgradePolicy` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_upgrade_policy() -> Weight {
+		Weight::from_parts(0, 0)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// PLACEHOLDER: only the storage accesses are accounted for.
+	/// Storage: `MoveModule::ReservedAddresses` (r:1 w:1)
+	/// Proof: `MoveModule::ReservedAddresses` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `MoveModule::VMStorageKeys` (r:1 w:0)
+	/// Proof: `MoveModule::VMStorageKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn add_reserved_address() -> Weight {
+		Weight::from_parts(0, 0)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// PLACEHOLDER: only the storage accesses are accounted for.
+	/// Storage: `MoveModule::ReservedAddresses` (r:1 w:1)
+	/// Proof: `MoveModule::ReservedAddresses` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_reserved_address() -> Weight {
+		Weight::from_parts(0, 0)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// PLACEHOLDER: the execution time is taken from `publish_module_generic`, whose benchmark
+	/// publishes the same bundles with one module per step.
+	/// Storage: `MoveModule::ReservedAddresses` (r:1 w:0)
+	/// Proof: `MoveModule::ReservedAddresses` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `MoveModule::ModuleUpgradePolicy` (r:25 w:0)
+	/// Proof: `MoveModule::ModuleUpgradePolicy` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `MoveModule::VMStorage` (r:25 w:25)
+	/// Proof: `MoveModule::VMStorage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `MoveModule::VMStorageKeys` (r:0 w:25)
+	/// Proof: `MoveModule::VMStorageKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 25]`.
+	fn publish_framework_bundle(n: u32, ) -> Weight {
+		Weight::from_parts(54_952_033, 0)
+			.saturating_add(Weight::from_parts(27_356_941, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// PLACEHOLDER: only the storage accesses are accounted for, assuming that all signers except
+	/// the cancelling one have signed the request.
+	/// Storage: `MoveModule::MultisigStorage` (r:1 w:1)
+	/// Proof: `MoveModule::MultisigStorage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `MoveModule::ChoreOnIdleStorage` (r:1 w:1)
+	/// Proof: `MoveModule::ChoreOnIdleStorage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `MoveModule::MultisigRequestsBySigner` (r:0 w:8)
+	/// Proof: `MoveModule::MultisigRequestsBySigner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Locks` (r:8 w:8)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:8 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:8 w:8)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 8]`.
+	fn cancel_multisig_request(s: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(s.into())))
+	}
+	/// PLACEHOLDER: only the storage accesses are accounted for.
+	/// Storage: `MoveModule::MultisigStorage` (r:1 w:1)
+	/// Proof: `MoveModule::MultisigStorage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn revoke_multisig_signature() -> Weight {
+		Weight::from_parts(0, 0)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
@@ -3,15 +3,51 @@ use std::sync::Arc;
 use codec::Codec;
 use frame_support::weights::Weight;
 use jsonrpsee::{
-    core::RpcResult,
+    core::{JsonValue, RpcResult},
     proc_macros::rpc,
     types::{error::ErrorCode, ErrorObjectOwned},
 };
-pub use pallet_move::api::{ModuleAbi, MoveApi as MoveRuntimeApi, MoveApiEstimation};
+use move_core_types::{parser::parse_struct_tag, vm_status::StatusCode};
+pub use pallet_move::api::{
+    ModuleAbi, MoveApi as MoveRuntimeApi, MoveApiBalanceChange, MoveApiChequeLimits,
+    MoveApiEstimation, MoveApiEvent, MoveApiModuleInfo, MoveApiMultisigRequest,
+    MoveApiMultisigSigner, MoveApiSimulation, MoveApiStorageChange, MoveApiValue, MoveApiWriteOp,
+};
 use serde::{Deserialize, Serialize};
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
-use sp_runtime::traits::Block as BlockT;
+use sp_runtime::{traits::Block as BlockT, DispatchError, ModuleError};
+
+/// JSON-RPC error codes of the Move RPC methods.
+pub mod error_codes {
+    /// The runtime API couldn't be called, e.g. because of an unknown block. The error data
+    /// contains the error message.
+    pub const RUNTIME_API_ERROR: i32 = 7000;
+    /// The Move pallet rejected the request. The error data contains the decoded
+    /// `DispatchError`, see [`DispatchErrorData`](super::DispatchErrorData).
+    pub const DISPATCH_ERROR: i32 = 7001;
+    /// The MoveVM failed to handle the request. The error data contains the error message of
+    /// the MoveVM, including its status code.
+    pub const MOVE_VM_ERROR: i32 = 7002;
+}
+
+/// Data of a [`DISPATCH_ERROR`](error_codes::DISPATCH_ERROR).
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DispatchErrorData {
+    /// Debug representation of the `DispatchError`.
+    pub error: String,
+    /// Index of the pallet which returned the error, in case of a module error.
+    pub module_index: Option<u8>,
+    /// Index of the error within the pallet, in case of a module error.
+    pub error_index: Option<u8>,
+    /// Name of the error variant, in case the error has been returned by the Move pallet,
+    /// e.g. `LinkerError` for a failed Move status code `LINKER_ERROR`.
+    pub pallet_error: Option<String>,
+    /// MoveVM status code, in case the error has been converted from a failed Move status.
+    pub vm_status_code: Option<u64>,
+    /// Name of the MoveVM status code, e.g. `LINKER_ERROR`.
+    pub vm_status: Option<String>,
+}
 
 /// Gas estimation information.
 #[derive(Clone, Serialize, Deserialize)]
@@ -23,6 +59,9 @@ pub struct Estimation {
     /// Substrate weight required for the complete extrinsic cost combined with the variable
     /// used gas indicated in the [`Estimation`] struct.
     pub total_weight_including_gas_used: Weight,
+    /// Minimum gas limit, which has been verified to succeed with metered gas. `None` if the
+    /// execution fails anyway.
+    pub recommended_gas_limit: Option<u32>,
 }
 
 impl From<MoveApiEstimation> for Estimation {
@@ -31,6 +70,260 @@ impl From<MoveApiEstimation> for Estimation {
             gas_used: estimate.gas_used,
             vm_status_code: estimate.vm_status_code,
             total_weight_including_gas_used: estimate.total_weight_including_gas_used,
+            recommended_gas_limit: estimate.recommended_gas_limit,
+        }
+    }
+}
+
+/// Change of a single entry in the MoveVM storage.
+#[derive(Clone, Serialize, Deserialize)]
+pub enum StorageChange {
+    /// A new entry has been added with the given value.
+    Added(Vec<u8>),
+    /// An existing entry has been modified to the given value.
+    Modified(Vec<u8>),
+    /// An existing entry has been deleted.
+    Deleted,
+}
+
+impl From<MoveApiStorageChange> for StorageChange {
+    fn from(change: MoveApiStorageChange) -> Self {
+        match change {
+            MoveApiStorageChange::Added(value) => Self::Added(value),
+            MoveApiStorageChange::Modified(value) => Self::Modified(value),
+            MoveApiStorageChange::Deleted => Self::Deleted,
+        }
+    }
+}
+
+/// Write operation on the MoveVM storage.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct WriteOp {
+    /// Key within the MoveVM storage.
+    pub key: Vec<u8>,
+    /// Applied change - values are BCS-encoded resources or module bytecode.
+    pub change: StorageChange,
+}
+
+impl From<MoveApiWriteOp> for WriteOp {
+    fn from(op: MoveApiWriteOp) -> Self {
+        Self {
+            key: op.key,
+            change: op.change.into(),
+        }
+    }
+}
+
+/// Balance change of a single account.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BalanceChange {
+    /// The affected account.
+    pub account: String,
+    /// Amount deposited to the account.
+    pub deposited: u128,
+    /// Amount withdrawn from the account.
+    pub withdrawn: u128,
+}
+
+/// Cheque limits of the signers of a script transaction during a gas estimation.
+#[derive(Clone, Serialize, Deserialize)]
+pub enum ChequeLimits<AccountId> {
+    /// Unlimited cheque limits, so insufficient balances don't show up in the estimation.
+    Unlimited,
+    /// The whole free balance of each signer.
+    FreeBalance,
+    /// Cheque limits of the listed signers, all other signers have a cheque limit of zero.
+    Limits(Vec<(AccountId, u128)>),
+}
+
+impl<AccountId> From<ChequeLimits<AccountId>> for MoveApiChequeLimits<AccountId> {
+    fn from(limits: ChequeLimits<AccountId>) -> Self {
+        match limits {
+            ChequeLimits::Unlimited => Self::Unlimited,
+            ChequeLimits::FreeBalance => Self::FreeBalance,
+            ChequeLimits::Limits(limits) => Self::Limits(limits),
+        }
+    }
+}
+
+/// Event emitted within Move code.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Event {
+    /// BCS-encoded type tag of the event.
+    pub type_tag: Vec<u8>,
+    /// BCS-encoded event data.
+    pub data: Vec<u8>,
+}
+
+impl From<MoveApiEvent> for Event {
+    fn from(event: MoveApiEvent) -> Self {
+        Self {
+            type_tag: event.type_tag,
+            data: event.data,
+        }
+    }
+}
+
+/// Simulation result of a script execution.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Simulation {
+    /// Gas estimation of the execution.
+    pub estimation: Estimation,
+    /// All changes which would be applied to the MoveVM storage.
+    pub write_set: Vec<WriteOp>,
+    /// All balance changes of involved accounts.
+    pub balance_changes: Vec<BalanceChange>,
+    /// All events emitted within Move code.
+    pub events: Vec<Event>,
+}
+
+impl<AccountId: std::fmt::Display> From<MoveApiSimulation<AccountId>> for Simulation {
+    fn from(simulation: MoveApiSimulation<AccountId>) -> Self {
+        let balance_changes = simulation
+            .balance_changes
+            .into_iter()
+            .map(|(account, change)| {
+                let (deposited, withdrawn) = match change {
+                    MoveApiBalanceChange::Deposited(amount) => (amount, 0),
+                    MoveApiBalanceChange::Withdrawn(amount) => (0, amount),
+                };
+                BalanceChange {
+                    account: account.to_string(),
+                    deposited,
+                    withdrawn,
+                }
+            })
+            .collect();
+
+        Self {
+            estimation: simulation.estimation.into(),
+            write_set: simulation.write_set.into_iter().map(Into::into).collect(),
+            balance_changes,
+            events: simulation.events.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Module published under an account.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ModuleInfo {
+    /// Name of the module.
+    pub name: String,
+    /// Blake2s-256 hash of the module bytecode, if requested.
+    pub bytecode_hash: Option<[u8; 32]>,
+}
+
+impl From<MoveApiModuleInfo> for ModuleInfo {
+    fn from(info: MoveApiModuleInfo) -> Self {
+        Self {
+            name: info.name,
+            bytecode_hash: info.bytecode_hash,
+        }
+    }
+}
+
+/// Signer of a pending multi-signer execution request.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MultisigSigner {
+    /// Account of the signer.
+    pub account: String,
+    /// Whether the signer has already signed the request.
+    pub approved: bool,
+    /// Cheque limit of the signer, which is zero until the signer has signed the request.
+    pub cheque_limit: u128,
+}
+
+impl<AccountId: std::fmt::Display> From<MoveApiMultisigSigner<AccountId>> for MultisigSigner {
+    fn from(signer: MoveApiMultisigSigner<AccountId>) -> Self {
+        Self {
+            account: signer.account.to_string(),
+            approved: signer.approved,
+            cheque_limit: signer.cheque_limit,
+        }
+    }
+}
+
+/// Pending multi-signer execution request.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MultisigRequest {
+    /// Hash of the call, which identifies the request.
+    pub call_hash: [u8; 32],
+    /// All signers of the request with their signature states.
+    pub signers: Vec<MultisigSigner>,
+    /// Block number at which the request gets removed, if it hasn't been executed until then.
+    pub expires_at: Option<u64>,
+}
+
+impl<AccountId: std::fmt::Display> From<MoveApiMultisigRequest<AccountId>> for MultisigRequest {
+    fn from(request: MoveApiMultisigRequest<AccountId>) -> Self {
+        Self {
+            call_hash: request.call_hash,
+            signers: request.signers.into_iter().map(Into::into).collect(),
+            expires_at: request.expires_at,
+        }
+    }
+}
+
+/// Converts a decoded resource value into JSON.
+///
+/// Structs become objects with their field names as keys. Integers which might not fit into a
+/// JSON number (`u64` and larger) are represented as decimal strings, `u256` values and addresses
+/// as hex strings.
+fn move_value_into_json(value: MoveApiValue) -> JsonValue {
+    match value {
+        MoveApiValue::Bool(value) => value.into(),
+        MoveApiValue::U8(value) => value.into(),
+        MoveApiValue::U16(value) => value.into(),
+        MoveApiValue::U32(value) => value.into(),
+        MoveApiValue::U64(value) => value.to_string().into(),
+        MoveApiValue::U128(value) => value.to_string().into(),
+        MoveApiValue::U256(mut value) => {
+            // Big-endian for a readable hex representation.
+            value.reverse();
+            to_hex(&value).into()
+        }
+        MoveApiValue::Address(address) => to_hex(&address).into(),
+        MoveApiValue::Vector(values) => values.into_iter().map(move_value_into_json).collect(),
+        MoveApiValue::Struct(fields) => JsonValue::Object(
+            fields
+                .into_iter()
+                .map(|(name, value)| (name, move_value_into_json(value)))
+                .collect(),
+        ),
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+    format!("0x{hex}")
+}
+
+/// Type tag of a resource, either BCS-encoded or in its string representation, e.g.
+/// `0xCAFE::Counter::Counter<u64>`.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ResourceTag {
+    /// BCS-encoded `StructTag`.
+    Bcs(Vec<u8>),
+    /// String representation of the `StructTag`.
+    Text(String),
+}
+
+impl ResourceTag {
+    /// Returns the BCS-encoded `StructTag`, parsing the string representation if necessary.
+    fn into_bcs(self) -> RpcResult<Vec<u8>> {
+        match self {
+            Self::Bcs(tag) => Ok(tag),
+            Self::Text(tag) => parse_struct_tag(&tag)
+                .ok()
+                .and_then(|tag| bcs::to_bytes(&tag).ok())
+                .ok_or_else(|| {
+                    ErrorObjectOwned::owned(
+                        ErrorCode::InvalidParams.code(),
+                        format!("invalid type tag: {tag}"),
+                        None::<()>,
+                    )
+                }),
         }
     }
 }
@@ -57,22 +350,78 @@ pub trait MoveApi<BlockHash, AccountId> {
     ) -> RpcResult<Estimation>;
 
     /// Estimate gas for executing Move script.
+    ///
+    /// The optional `cheque_limits` come last, so that existing clients passing the block hash
+    /// as the second parameter keep working.
     #[method(name = "mvm_estimateGasExecuteScript")]
     fn estimate_gas_execute_script(
         &self,
         transaction: Vec<u8>,
         at: Option<BlockHash>,
+        cheque_limits: Option<ChequeLimits<AccountId>>,
     ) -> RpcResult<Estimation>;
 
+    /// Simulate the execution of a Move script without applying any changes.
+    #[method(name = "mvm_simulateExecuteScript")]
+    fn simulate_execute_script(
+        &self,
+        transaction: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Simulation>;
+
     /// Get resource.
     #[method(name = "mvm_getResource")]
     fn get_resource(
         &self,
         account: AccountId,
-        tag: Vec<u8>,
+        tag: ResourceTag,
         at: Option<BlockHash>,
     ) -> RpcResult<Option<Vec<u8>>>;
 
+    /// Get resource as JSON, decoded with the layout of its struct.
+    #[method(name = "mvm_getResourceJSON")]
+    fn get_resource_json(
+        &self,
+        account: AccountId,
+        tag: ResourceTag,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<JsonValue>>;
+
+    /// List the type tags of all resources stored under an account.
+    #[method(name = "mvm_listResources")]
+    fn list_resources(
+        &self,
+        account: AccountId,
+        start: Option<Vec<u8>>,
+        limit: Option<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Vec<u8>>>;
+
+    /// List the modules published under an account.
+    #[method(name = "mvm_listModules")]
+    fn list_modules(
+        &self,
+        account: AccountId,
+        with_hashes: Option<bool>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ModuleInfo>>;
+
+    /// Get a pending multi-signer execution request by its call hash.
+    #[method(name = "mvm_getMultisigRequest")]
+    fn get_multisig_request(
+        &self,
+        call_hash: [u8; 32],
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<MultisigRequest>>;
+
+    /// List all pending multi-signer execution requests an account is a signer of.
+    #[method(name = "mvm_pendingMultisigRequestsFor")]
+    fn pending_multisig_requests_for(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<MultisigRequest>>;
+
     /// Get module ABI using address.
     #[method(name = "mvm_getModuleABI")]
     fn get_module_abi(
@@ -121,16 +470,19 @@ where
         bytecode: Vec<u8>,
         at: Option<<Block as BlockT>::Hash>,
     ) -> RpcResult<Estimation> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
         let api = self.client.runtime_api();
-        let res = api
-            .estimate_gas_publish_module(
-                at.unwrap_or_else(|| self.client.info().best_hash),
-                account,
-                bytecode,
-            )
-            .map_err(runtime_error_into_rpc_err)?;
+        let res = if self.api_version::<AccountId>(at)? < 2 {
+            #[allow(deprecated)]
+            let res = api.estimate_gas_publish_module_before_version_2(at, account, bytecode);
+            res.map(|res| res.map(Into::into))
+        } else {
+            api.estimate_gas_publish_module(at, account, bytecode)
+        }
+        .map_err(runtime_error_into_rpc_err)?;
 
-        let move_api_estimation = res.map_err(runtime_error_into_rpc_err)?;
+        let move_api_estimation =
+            res.map_err(|e| self.dispatch_error_into_rpc_err::<AccountId>(at, e))?;
 
         Ok(Estimation::from(move_api_estimation))
     }
@@ -141,53 +493,182 @@ where
         bytecode: Vec<u8>,
         at: Option<<Block as BlockT>::Hash>,
     ) -> RpcResult<Estimation> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let api = self.client.runtime_api();
+        let res = if self.api_version::<AccountId>(at)? < 2 {
+            #[allow(deprecated)]
+            let res = api.estimate_gas_publish_bundle_before_version_2(at, account, bytecode);
+            res.map(|res| res.map(Into::into))
+        } else {
+            api.estimate_gas_publish_bundle(at, account, bytecode)
+        }
+        .map_err(runtime_error_into_rpc_err)?;
+
+        let move_api_estimation =
+            res.map_err(|e| self.dispatch_error_into_rpc_err::<AccountId>(at, e))?;
+
+        Ok(Estimation::from(move_api_estimation))
+    }
+
+    fn estimate_gas_execute_script(
+        &self,
+        transaction: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+        cheque_limits: Option<ChequeLimits<AccountId>>,
+    ) -> RpcResult<Estimation> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let api = self.client.runtime_api();
+        let res = if self.api_version::<AccountId>(at)? < 2 {
+            // Older runtimes always estimate with unlimited cheque limits.
+            if cheque_limits.is_some() {
+                return Err(ErrorObjectOwned::owned(
+                    ErrorCode::InvalidParams.code(),
+                    "cheque limits are not supported by the runtime",
+                    None::<()>,
+                ));
+            }
+            #[allow(deprecated)]
+            let res = api.estimate_gas_execute_script_before_version_2(at, transaction);
+            res.map(|res| res.map(Into::into))
+        } else {
+            let cheque_limits = cheque_limits.map_or(MoveApiChequeLimits::Unlimited, Into::into);
+            api.estimate_gas_execute_script_with_cheques(at, transaction, cheque_limits)
+        }
+        .map_err(runtime_error_into_rpc_err)?;
+
+        let move_api_estimation =
+            res.map_err(|e| self.dispatch_error_into_rpc_err::<AccountId>(at, e))?;
+
+        Ok(Estimation::from(move_api_estimation))
+    }
+
+    fn simulate_execute_script(
+        &self,
+        transaction: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Simulation> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let api = self.client.runtime_api();
+        let res = api
+            .simulate_execute_script(at, transaction)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        let move_api_simulation =
+            res.map_err(|e| self.dispatch_error_into_rpc_err::<AccountId>(at, e))?;
+
+        Ok(Simulation::from(move_api_simulation))
+    }
+
+    fn get_resource(
+        &self,
+        account: AccountId,
+        tag: ResourceTag,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<Vec<u8>>> {
+        let tag = tag.into_bcs()?;
         let api = self.client.runtime_api();
         let res = api
-            .estimate_gas_publish_bundle(
+            .get_resource(
                 at.unwrap_or_else(|| self.client.info().best_hash),
                 account,
-                bytecode,
+                tag,
             )
             .map_err(runtime_error_into_rpc_err)?;
 
-        let move_api_estimation = res.map_err(runtime_error_into_rpc_err)?;
+        res.map_err(move_error_into_rpc_err)
+    }
 
-        Ok(Estimation::from(move_api_estimation))
+    fn get_resource_json(
+        &self,
+        account: AccountId,
+        tag: ResourceTag,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<JsonValue>> {
+        let tag = tag.into_bcs()?;
+        let api = self.client.runtime_api();
+        let res = api
+            .get_resource_value(
+                at.unwrap_or_else(|| self.client.info().best_hash),
+                account,
+                tag,
+            )
+            .map_err(runtime_error_into_rpc_err)?;
+
+        let value = res.map_err(move_error_into_rpc_err)?;
+
+        Ok(value.map(move_value_into_json))
     }
 
-    fn estimate_gas_execute_script(
+    fn list_resources(
         &self,
-        transaction: Vec<u8>,
+        account: AccountId,
+        start: Option<Vec<u8>>,
+        limit: Option<u32>,
         at: Option<<Block as BlockT>::Hash>,
-    ) -> RpcResult<Estimation> {
+    ) -> RpcResult<Vec<Vec<u8>>> {
         let api = self.client.runtime_api();
         let res = api
-            .estimate_gas_execute_script(
+            .list_resources(
                 at.unwrap_or_else(|| self.client.info().best_hash),
-                transaction,
+                account,
+                start,
+                limit,
             )
             .map_err(runtime_error_into_rpc_err)?;
 
-        let move_api_estimation = res.map_err(runtime_error_into_rpc_err)?;
+        res.map_err(move_error_into_rpc_err)
+    }
 
-        Ok(Estimation::from(move_api_estimation))
+    fn list_modules(
+        &self,
+        account: AccountId,
+        with_hashes: Option<bool>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<ModuleInfo>> {
+        let api = self.client.runtime_api();
+        let res = api
+            .list_modules(
+                at.unwrap_or_else(|| self.client.info().best_hash),
+                account,
+                with_hashes.unwrap_or_default(),
+            )
+            .map_err(runtime_error_into_rpc_err)?;
+
+        let modules = res.map_err(move_error_into_rpc_err)?;
+
+        Ok(modules.into_iter().map(Into::into).collect())
     }
 
-    fn get_resource(
+    fn get_multisig_request(
+        &self,
+        call_hash: [u8; 32],
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<MultisigRequest>> {
+        let api = self.client.runtime_api();
+        let request = api
+            .get_multisig_request(
+                at.unwrap_or_else(|| self.client.info().best_hash),
+                call_hash,
+            )
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(request.map(Into::into))
+    }
+
+    fn pending_multisig_requests_for(
         &self,
         account: AccountId,
-        tag: Vec<u8>,
         at: Option<<Block as BlockT>::Hash>,
-    ) -> RpcResult<Option<Vec<u8>>> {
+    ) -> RpcResult<Vec<MultisigRequest>> {
         let api = self.client.runtime_api();
-        let res = api.get_resource(
-            at.unwrap_or_else(|| self.client.info().best_hash),
-            account,
-            tag,
-        );
+        let requests = api
+            .pending_multisig_requests_for(
+                at.unwrap_or_else(|| self.client.info().best_hash),
+                account,
+            )
+            .map_err(runtime_error_into_rpc_err)?;
 
-        // Currently, there is always correct value returned so it's safe to unwrap here.
-        res.unwrap().map_err(runtime_error_into_rpc_err)
+        Ok(requests.into_iter().map(Into::into).collect())
     }
 
     fn get_module_abi(
@@ -197,14 +678,15 @@ where
         at: Option<<Block as BlockT>::Hash>,
     ) -> RpcResult<Option<ModuleAbi>> {
         let api = self.client.runtime_api();
-        let res = api.get_module_abi(
-            at.unwrap_or_else(|| self.client.info().best_hash),
-            address,
-            name.to_string(),
-        );
+        let res = api
+            .get_module_abi(
+                at.unwrap_or_else(|| self.client.info().best_hash),
+                address,
+                name.to_string(),
+            )
+            .map_err(runtime_error_into_rpc_err)?;
 
-        // Currently, there is always correct value returned so it's safe to unwrap here.
-        res.unwrap().map_err(runtime_error_into_rpc_err)
+        res.map_err(move_error_into_rpc_err)
     }
 
     fn get_module(
@@ -214,18 +696,90 @@ where
         at: Option<<Block as BlockT>::Hash>,
     ) -> RpcResult<Option<Vec<u8>>> {
         let api = self.client.runtime_api();
-        let res = api.get_module(
-            at.unwrap_or_else(|| self.client.info().best_hash),
-            address,
-            name.to_string(),
-        );
+        let res = api
+            .get_module(
+                at.unwrap_or_else(|| self.client.info().best_hash),
+                address,
+                name.to_string(),
+            )
+            .map_err(runtime_error_into_rpc_err)?;
 
-        // Currently, there is always correct value returned so it's safe to unwrap here.
-        res.unwrap().map_err(runtime_error_into_rpc_err)
+        res.map_err(move_error_into_rpc_err)
+    }
+}
+
+impl<C, Block> MovePallet<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block>,
+{
+    /// Returns the version of the Move runtime API at the given block.
+    fn api_version<AccountId: Codec>(&self, at: <Block as BlockT>::Hash) -> RpcResult<u32>
+    where
+        C::Api: MoveRuntimeApi<Block, AccountId>,
+    {
+        self.client
+            .runtime_api()
+            .api_version::<dyn MoveRuntimeApi<Block, AccountId>>(at)
+            .map_err(runtime_error_into_rpc_err)?
+            .ok_or_else(|| runtime_error_into_rpc_err("the Move runtime API is not available"))
+    }
+
+    /// Converts a dispatch error into an RPC error, where errors of the Move pallet get decoded
+    /// into their names.
+    fn dispatch_error_into_rpc_err<AccountId: Codec>(
+        &self,
+        at: <Block as BlockT>::Hash,
+        error: DispatchError,
+    ) -> ErrorObjectOwned
+    where
+        C::Api: MoveRuntimeApi<Block, AccountId>,
+    {
+        let (module_index, error_index) = match &error {
+            DispatchError::Module(ModuleError { index, error, .. }) => {
+                (Some(*index), Some(error[0]))
+            }
+            _ => (None, None),
+        };
+        let debug = format!("{error:?}");
+        let api = self.client.runtime_api();
+        let pallet_error = api.error_name(at, error).ok().flatten();
+        let vm_status_code = api.error_vm_status(at, error).ok().flatten();
+        let vm_status = vm_status_code
+            .and_then(|code| StatusCode::try_from(code).ok())
+            .map(|status| format!("{status:?}"));
+
+        let data = DispatchErrorData {
+            error: debug,
+            module_index,
+            error_index,
+            pallet_error,
+            vm_status_code,
+            vm_status,
+        };
+
+        ErrorObjectOwned::owned(
+            error_codes::DISPATCH_ERROR,
+            "Request rejected by the runtime",
+            Some(data),
+        )
     }
 }
 
 /// Converts a runtime trap into an RPC error.
-fn runtime_error_into_rpc_err(_: impl std::fmt::Debug) -> ErrorObjectOwned {
-    ErrorCode::InternalError.into()
+fn runtime_error_into_rpc_err(error: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(
+        error_codes::RUNTIME_API_ERROR,
+        "Runtime API call failed",
+        Some(format!("{error:?}")),
+    )
+}
+
+/// Converts an error message of the MoveVM into an RPC error.
+fn move_error_into_rpc_err(error: Vec<u8>) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(
+        error_codes::MOVE_VM_ERROR,
+        "Request failed in the MoveVM",
+        Some(String::from_utf8_lossy(&error)),
+    )
 }